@@ -1,12 +1,12 @@
 use std::collections::{BTreeSet, HashMap};
 
-use heck::ToSnakeCase;
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use itertools::Itertools;
 use proc_macro::TokenStream;
 use quote::TokenStreamExt;
 use syn::{
-    Expr, GenericArgument, GenericParam, Generics, Ident, Lifetime, Type, TypePath, ext,
-    parse_macro_input, parse_quote,
+    GenericArgument, GenericParam, Generics, Ident, Lifetime, Type, TypePath, parse_macro_input,
+    parse_quote,
 };
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -62,6 +62,56 @@ impl syn::parse::Parse for ConversionType {
         }
     }
 }
+/// The arguments of a `conversion` attribute.
+///
+/// `#[conversion(as = Foo, name = "owner")]` gives the conversion a label,
+/// so two conversions into the same type can be told apart.
+struct ConversionAttr {
+    conversion: ConversionType,
+    label: Option<syn::LitStr>,
+}
+impl syn::parse::Parse for ConversionAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Token![as]) {
+            let _: syn::Token![as] = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            let ty = input.parse()?;
+            let label = if input.parse::<syn::Token![,]>().is_ok() {
+                let name: Ident = input.parse()?;
+                if name != "name" {
+                    return Err(syn::Error::new(name.span(), "expected `name`"));
+                }
+                let _: syn::Token![=] = input.parse()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            Ok(ConversionAttr {
+                conversion: ConversionType::Type(ty),
+                label,
+            })
+        } else {
+            Ok(ConversionAttr {
+                conversion: input.parse()?,
+                label: None,
+            })
+        }
+    }
+}
+impl ConversionAttr {
+    /// Wraps labeled conversions in `Labeled`, with a marker type generated from the label.
+    fn resolve(self, name: &Ident, label_markers: &mut BTreeSet<Ident>) -> ConversionType {
+        match (self.conversion, self.label) {
+            (ConversionType::Type(ty), Some(label)) => {
+                let marker = quote::format_ident!("{name}{}", label.value().to_upper_camel_case());
+                label_markers.insert(marker.clone());
+                ConversionType::Type(parse_quote!(state_validation::Labeled<#ty, #marker>))
+            }
+            (conversion, _) => conversion,
+        }
+    }
+}
+
 impl quote::ToTokens for ConversionType {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -132,6 +182,7 @@ impl quote::ToTokens for ConversionType {
 ///
 /// The `conversion` attribute can be used multiple times on a single field for different conversion types:
 /// ```
+/// # use state_validation::StateFilterConversion;
 /// #[derive(StateFilterConversion)]
 /// struct ExampleStruct {
 ///     #[conversion(AdminUser)]
@@ -142,17 +193,224 @@ impl quote::ToTokens for ConversionType {
 /// # struct AdminUser;
 /// # struct UserWithData;
 /// ```
+///
+/// If a field has more than one conversion into the same type, give each of them a label:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// struct ExampleStruct {
+///     #[conversion(as = User, name = "owner")]
+///     #[conversion(as = User, name = "editor")]
+///     some_value: UserID,
+/// }
+/// # struct UserID;
+/// # struct User;
+/// ```
+/// Labeled conversions are wrapped in `Labeled<User, _>`, using the generated markers
+/// `ExampleStructOwner` and `ExampleStructEditor`.
+/// A filter's output is labeled with `WithLabel<Filter, ExampleStructOwner>`.
+///
+/// To generate only the splits that are used, list them with `targets` on the struct:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// #[conversion(targets(UserID, (AdminUser, Username)))]
+/// struct ExampleStruct {
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+///     username: Username,
+/// }
+/// # struct UserID;
+/// # struct AdminUser;
+/// # struct Username;
+/// ```
+/// Each target is a type, or tuple of types in any order, that the struct can be split into.
+/// The types may be those of the fields or those given by their `conversion` attributes,
+/// so `targets` only narrows down which splits are generated and never adds new ones.
+///
+/// Since the generated code grows quickly with the amount of fields, a struct may have at most
+/// six fields, including extra fields given by `conversion` on the struct.
+/// Fields which no filter needs, such as an id passed through to the output, can be left out
+/// of the splits with `skip`, so they are kept in every remainder and do not count toward the limit:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// struct ExampleStruct {
+///     #[conversion(skip)]
+///     request_id: RequestID,
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+/// }
+/// # struct RequestID;
+/// # struct UserID;
+/// # struct AdminUser;
+/// ```
+/// Otherwise, raise the limit with `max_fields` if compile times allow it:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// #[conversion(max_fields = 8)]
+/// struct ExampleStruct {
+///     // ...
+/// }
+/// ```
+///
+/// Use `field_paths` on the struct to implement `FieldPath` for each type a field can be split into,
+/// so a failing filter can be traced back to the name of its field:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// #[conversion(field_paths)]
+/// struct ExampleStruct {
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+///     username: Username,
+/// }
+/// # struct UserID;
+/// # struct AdminUser;
+/// # struct Username;
+/// ```
+/// Types shared by more than one field are skipped, since they do not belong to a single field.
+///
+/// Use `introspect` on the struct to generate the constant `CONVERSIONS`,
+/// listing every type its fields can be split into, as written by `stringify!`,
+/// to find out which `conversion` is missing when a chain does not compile:
+/// ```
+/// # use state_validation::StateFilterConversion;
+/// #[derive(StateFilterConversion)]
+/// #[conversion(introspect)]
+/// struct ExampleStruct {
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+///     username: Username,
+/// }
+/// # struct UserID;
+/// # struct AdminUser;
+/// # struct Username;
+/// assert_eq!(ExampleStruct::CONVERSIONS, ["UserID", "AdminUser", "Username"]);
+/// ```
+///
+/// A field of `Option<T>` is split out as `Option<T>`, so its conversion is given as the output
+/// of the filter run on it. Each conversion of an `Option` field may also be held wrapped in `Option`,
+/// so `#[conversion(ValidEmail)]` gives both `ValidEmail` and `Option<ValidEmail>`.
+/// `Optional<Filter>` keeps `None` as `None`, while `Required` splits out the `T` and fails on `None`:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// struct ExampleStruct {
+///     #[conversion(ValidEmail)]
+///     email: Option<Email>,
+///     #[conversion(Username)]
+///     username: Option<Username>,
+/// }
+/// # struct Email;
+/// # struct ValidEmail;
+/// # struct Username;
+/// ```
+///
+/// Lifetime parameters of the struct are carried over to the structs generated for its splits,
+/// so inputs borrowing from a request can be split without copying:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// struct ExampleStruct<'a> {
+///     #[conversion(Token<'a>)]
+///     token: &'a str,
+///     user_id: UserID,
+/// }
+/// # struct Token<'a>(&'a str);
+/// # struct UserID;
+/// ```
+///
+/// Use `derive` on the struct to derive traits, such as `Clone`, on the structs generated for its splits,
+/// so the values in the middle of a chain can be cloned:
+/// ```ignore
+/// #[derive(StateFilterConversion, Clone)]
+/// #[conversion(derive(Clone))]
+/// struct ExampleStruct {
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+/// }
+/// # #[derive(Clone)]
+/// # struct UserID;
+/// # #[derive(Clone)]
+/// # struct AdminUser;
+/// ```
+///
+/// Use `name` on the struct to give a stable name to the struct generated for some of its fields,
+/// each converted into one of `types`, instead of its generated name which changes as fields are added:
+/// ```ignore
+/// #[derive(StateFilterConversion)]
+/// #[conversion(name = "AdminWithUsername", types(AdminUser, Username))]
+/// struct ExampleStruct {
+///     #[conversion(AdminUser)]
+///     user_id: UserID,
+///     username: Username,
+/// }
+/// # struct UserID;
+/// # struct AdminUser;
+/// # struct Username;
+/// ```
+///
+/// The structs generated to hold the outputs of a chain have a `new` function taking their fields,
+/// and implement `FromFields` for a tuple of their fields, so they can be built in tests.
+/// Fields left out with `skip` come after the others.
 #[proc_macro_derive(StateFilterConversion, attributes(conversion))]
 pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
     let name = &ast.ident;
     let state_conversions = match &ast.data {
         syn::Data::Struct(s) => {
-            let fields_count = s.fields.len();
+            let (skipped_fields, split_fields): (Vec<_>, Vec<_>) = s
+                .fields
+                .iter()
+                .partition(|field| field.attrs.iter().any(is_skip));
+            if let Some(field) = skipped_fields.iter().find(|field| {
+                field
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("conversion") && !is_skip(attr))
+            }) {
+                return syn::Error::new_spanned(
+                    field.ident.as_ref(),
+                    "a field with `#[conversion(skip)]` is never split out, so it cannot have conversions",
+                )
+                .to_compile_error()
+                .into();
+            }
+            let skipped_names: Vec<_> = skipped_fields
+                .iter()
+                .map(|field| field.ident.as_ref().expect("expected a named field"))
+                .collect();
+            let skipped_types: Vec<_> = skipped_fields.iter().map(|field| &field.ty).collect();
+            let mut skipped_generics = Generics::default();
+            for ty in skipped_types.iter() {
+                skipped_generics = merge_generics(
+                    skipped_generics,
+                    &extract_generics_from_type(ty, &ast.generics),
+                );
+            }
+            let fields_count = split_fields.len();
             let mut state_conversions = Vec::with_capacity(fields_count);
+            let mut label_markers = BTreeSet::new();
+            let targets: Option<Vec<Type>> = ast
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("conversion"))
+                .filter_map(parse_targets)
+                .reduce(|mut a, b| {
+                    a.extend(b);
+                    a
+                });
+            let field_paths = ast.attrs.iter().any(is_field_paths);
+            let introspect = ast.attrs.iter().any(is_introspect);
+            let aliases: Vec<(Ident, Vec<Type>)> =
+                ast.attrs.iter().filter_map(parse_alias).collect();
+            let derives: Vec<syn::Path> = ast
+                .attrs
+                .iter()
+                .filter_map(parse_derives)
+                .flatten()
+                .collect();
+            let max_fields = match ast.attrs.iter().find_map(parse_max_fields).transpose() {
+                Ok(max_fields) => max_fields.unwrap_or(DEFAULT_MAX_FIELDS),
+                Err(error) => return error.to_compile_error().into(),
+            };
             let (iter, extra_fields_count) = {
-                let mut iter: Vec<_> = s
-                    .fields
+                let mut iter: Vec<_> = split_fields
                     .iter()
                     .enumerate()
                     .map(|(i, field)| {
@@ -172,8 +430,9 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                             .filter(|attr| attr.path().is_ident("conversion"))
                         {
                             let f = attr
-                                .parse_args::<ConversionType>()
-                                .expect("expected a conversion type");
+                                .parse_args::<ConversionAttr>()
+                                .expect("expected a conversion type")
+                                .resolve(name, &mut label_markers);
                             let generics = match &f {
                                 ConversionType::Type(ty) => {
                                     extract_generics_from_type(ty, &ast.generics)
@@ -182,14 +441,32 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                                     parse_quote!(<#(#generic_ident),*>)
                                 }
                             };
-                            all_conversion_fields.push((
-                                field_name.clone(),
-                                ConversionSort {
-                                    sort_number: i,
-                                    ty: f,
-                                },
-                                generics,
-                            ));
+                            // An optional field can also hold the conversion wrapped in `Option`,
+                            // for a filter which passes `None` through.
+                            let optional = match &f {
+                                ConversionType::Type(ty) if option_inner_type(ty).is_none() => {
+                                    with_option_inner_type(&field.ty, ty).map(|optional| {
+                                        (ConversionType::Type(optional), generics.clone())
+                                    })
+                                }
+                                _ => None,
+                            };
+                            for (f, generics) in std::iter::once((f, generics)).chain(optional) {
+                                let f_name = quote::quote!(#f).to_string();
+                                if all_conversion_fields.iter().any(|(_, existing, _)| {
+                                    quote::quote!(#existing).to_string() == f_name
+                                }) {
+                                    continue;
+                                }
+                                all_conversion_fields.push((
+                                    field_name.clone(),
+                                    ConversionSort {
+                                        sort_number: i,
+                                        ty: f,
+                                    },
+                                    generics,
+                                ));
+                            }
                         }
                         all_conversion_fields
                     })
@@ -197,21 +474,35 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                 let extra_struct_fields: Vec<_> = ast
                     .attrs
                     .into_iter()
-                    .filter(|attr| attr.path().is_ident("conversion"))
+                    .filter(|attr| {
+                        attr.path().is_ident("conversion")
+                            && parse_targets(attr).is_none()
+                            && !is_field_paths(attr)
+                            && !is_introspect(attr)
+                            && parse_max_fields(attr).is_none()
+                            && parse_derives(attr).is_none()
+                            && parse_alias(attr).is_none()
+                    })
                     .enumerate()
                     .map(|(i, attr)| {
-                        let f = attr
-                            .parse_args::<ConversionType>()
+                        let attr = attr
+                            .parse_args::<ConversionAttr>()
                             .expect("expected a conversion type");
-                        let (field_name, generics) = match &f {
-                            ConversionType::Type(ty) => {
+                        let label = attr.label.clone();
+                        let f = attr.resolve(name, &mut label_markers);
+                        let (field_name, generics) = match (&f, label) {
+                            (ConversionType::Type(ty), Some(label)) => (
+                                quote::format_ident!("{}", label.value().to_snake_case()),
+                                extract_generics_from_type(ty, &ast.generics),
+                            ),
+                            (ConversionType::Type(ty), None) => {
                                 let ident = type_to_ident(ty);
                                 (
                                     quote::format_ident!("{}", ident.to_string().to_snake_case()),
                                     extract_generics_from_type(ty, &ast.generics),
                                 )
                             }
-                            ConversionType::Generic { generic_ident, ty } => {
+                            (ConversionType::Generic { generic_ident, ty }, _) => {
                                 let ident = type_to_ident(ty);
                                 (
                                     quote::format_ident!("{}", ident.to_string().to_snake_case()),
@@ -234,7 +525,34 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                 iter.extend(extra_struct_fields);
                 (iter, extra_fields_count)
             };
+            if iter.len() > max_fields {
+                return syn::Error::new(
+                    name.span(),
+                    format!(
+                        "`{name}` has {} fields to split, including extra fields from `conversion`, \
+                        but at most {max_fields} are allowed since every way of splitting them is generated; \
+                        leave out the fields no filter needs with `#[conversion(skip)]`, \
+                        or raise the limit with `#[conversion(max_fields = {})]`",
+                        iter.len(),
+                        iter.len(),
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+            if field_paths {
+                create_field_paths(
+                    &mut state_conversions,
+                    &ast.generics,
+                    name,
+                    &iter[..fields_count],
+                );
+            }
+            if introspect {
+                create_introspection(&mut state_conversions, &ast.generics, name, &iter);
+            }
             let mut combination_names = HashMap::new();
+            let mut combination_generics = HashMap::new();
             let mut remainder_names = HashMap::new();
             let mut i = 0;
             for powerset in iter.iter().powerset() {
@@ -257,17 +575,51 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                     for g in field_generics {
                         generics = merge_generics(generics, g);
                     }
+                    let generics = merge_generics(generics, &skipped_generics);
+                    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
                     let q = quote::quote! {
+                        #[derive(#(#derives),*)]
                         pub struct #combination_struct_name #generics {
-                            #(pub #field_names: #field_types),*
+                            #(pub #field_names: #field_types,)*
+                            #(pub #skipped_names: #skipped_types,)*
+                        }
+                        impl #impl_generics #combination_struct_name #ty_generics #where_clause {
+                            #[allow(clippy::too_many_arguments)]
+                            pub fn new(#(#field_names: #field_types,)* #(#skipped_names: #skipped_types,)*) -> Self {
+                                #combination_struct_name {
+                                    #(#field_names,)*
+                                    #(#skipped_names,)*
+                                }
+                            }
+                        }
+                        impl #impl_generics state_validation::FromFields<(#(#field_types,)* #(#skipped_types,)*)> for #combination_struct_name #ty_generics #where_clause {
+                            fn from_fields((#(#field_names,)* #(#skipped_names,)*): (#(#field_types,)* #(#skipped_types,)*)) -> Self {
+                                #combination_struct_name {
+                                    #(#field_names,)*
+                                    #(#skipped_names,)*
+                                }
+                            }
                         }
                     };
                     state_conversions.push(q);
                     field_types.sort();
+                    combination_generics.insert(field_types.clone(), generics.clone());
                     combination_names.insert(field_types, combination_struct_name);
                     i += 1;
                 }
             }
+            for (alias, types) in aliases {
+                match create_alias(
+                    &alias,
+                    &types,
+                    &iter,
+                    &combination_names,
+                    &combination_generics,
+                ) {
+                    Ok(q) => state_conversions.push(q),
+                    Err(error) => return error.to_compile_error().into(),
+                }
+            }
             let mut i = 0;
             for powerset in iter.iter().powerset() {
                 for (field_names, mut field_types, field_generics) in
@@ -289,9 +641,12 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                     for g in field_generics {
                         generics = merge_generics(generics, g);
                     }
+                    let generics = merge_generics(generics, &skipped_generics);
                     let q = quote::quote! {
+                        #[derive(#(#derives),*)]
                         pub struct #remainder_struct_name #generics {
-                            #(#field_names: #field_types),*
+                            #(#field_names: #field_types,)*
+                            #(#skipped_names: #skipped_types,)*
                         }
                     };
                     state_conversions.push(q);
@@ -300,15 +655,65 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                     i += 1;
                 }
             }
-            create_original_conversion_combinations(
+            let allowed_splits = match targets
+                .map(|targets| {
+                    targets
+                        .iter()
+                        .map(|target| {
+                            let target_types = match target {
+                                Type::Tuple(tuple) => tuple.elems.iter().collect(),
+                                ty => vec![ty],
+                            };
+                            let mut split = target_types
+                                .into_iter()
+                                .map(|target_type| {
+                                    let target_type_name = quote::quote!(#target_type).to_string();
+                                    iter.iter()
+                                        .flatten()
+                                        .find(|(_, field_type, _)| {
+                                            quote::quote!(#field_type).to_string() == target_type_name
+                                        })
+                                        .map(|(field_name, ..)| field_name.clone())
+                                        .ok_or_else(|| {
+                                            syn::Error::new_spanned(
+                                                target_type,
+                                                format!(
+                                                    "expected a field convertible to `{target_type_name}`"
+                                                ),
+                                            )
+                                        })
+                                })
+                                .collect::<syn::Result<Vec<_>>>()?;
+                            split.sort();
+                            Ok(split)
+                        })
+                        .collect::<syn::Result<Vec<_>>>()
+                })
+                .transpose()
+            {
+                Ok(allowed_splits) => allowed_splits,
+                Err(error) => return error.to_compile_error().into(),
+            };
+            let is_allowed_split = |field_names: &[Ident]| {
+                let mut field_names = field_names.to_vec();
+                field_names.sort();
+                allowed_splits
+                    .as_ref()
+                    .is_none_or(|allowed_splits| allowed_splits.contains(&field_names))
+            };
+            if let Err(error) = create_original_conversion_combinations(
                 &mut state_conversions,
+                &is_allowed_split,
                 &ast.generics,
                 &combination_names,
                 &remainder_names,
                 name,
-                &s.fields,
-                ast.generics.clone(),
-            );
+                &split_fields,
+                &skipped_names,
+                &skipped_generics,
+            ) {
+                return error.to_compile_error().into();
+            }
             let cartesian_product = iter.iter().multi_cartesian_product().map(|f| {
                 let mut field_names = Vec::with_capacity(f.len());
                 let mut field_types = Vec::with_capacity(f.len());
@@ -320,23 +725,24 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                 }
                 (field_names, field_types, generics)
             });
-            for (k, (field_names, field_types, field_generics)) in cartesian_product.enumerate() {
+            for (field_names, field_types, field_generics) in cartesian_product {
                 let mut all_field_generics = Generics::default();
                 for field_generics in field_generics.iter() {
                     all_field_generics = merge_generics(all_field_generics, field_generics);
                 }
+                let all_field_generics = merge_generics(all_field_generics, &skipped_generics);
                 let fields_name_type_generics: Vec<_> = field_names
                     .clone()
                     .into_iter()
-                    .zip(field_types.clone().into_iter())
-                    .zip(field_generics.clone().into_iter())
+                    .zip(field_types.clone())
+                    .zip(field_generics.clone())
                     .collect();
                 for count in 0..=(fields_count + extra_fields_count) {
                     for f in fields_name_type_generics.iter().combinations(count) {
                         for (
                             current_field_names,
                             current_field_types,
-                            current_field_generics,
+                            _current_field_generics,
                             other_field_names,
                             other_field_types,
                             other_field_generics,
@@ -374,32 +780,45 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                                 other_field_generics,
                             )
                         }) {
+                            if !is_allowed_split(&current_field_names) {
+                                continue;
+                            }
                             let r = current_field_types
                                 .iter()
                                 .chain(other_field_types.iter())
                                 .cloned()
                                 .sorted()
                                 .collect::<Vec<_>>();
-                            let combined_struct_name = combination_names
-                                .get(&r)
-                                .expect(&format!("0: expected a combined struct: {:?}", r));
+                            let Some(combined_struct_name) = combination_names.get(&r) else {
+                                return internal_error(name, "combination", &r)
+                                    .to_compile_error()
+                                    .into();
+                            };
                             let remainder_struct_name = {
                                 let mut other_field_types = other_field_types.clone();
                                 other_field_types.sort();
-                                remainder_names.get(&other_field_types).unwrap()
+                                let Some(remainder_struct_name) =
+                                    remainder_names.get(&other_field_types)
+                                else {
+                                    return internal_error(name, "remainder", &other_field_types)
+                                        .to_compile_error()
+                                        .into();
+                                };
+                                remainder_struct_name
                             };
                             let mut o = Generics::default();
                             for other_field_generics in other_field_generics {
                                 o = merge_generics(o, &other_field_generics);
                             }
-                            let other_field_generics = o;
+                            let other_field_generics = merge_generics(o, &skipped_generics);
                             let q = quote::quote! {
                                 impl #all_field_generics state_validation::StateFilterInputCombination<(#(#current_field_types),*)> for #remainder_struct_name #other_field_generics {
                                     type Combined = #combined_struct_name #all_field_generics;
                                     fn combine(self, (#(#current_field_names),*): (#(#current_field_types),*)) -> Self::Combined {
                                         #combined_struct_name {
                                             #(#current_field_names,)*
-                                            #(#other_field_names: self.#other_field_names),*
+                                            #(#other_field_names: self.#other_field_names,)*
+                                            #(#skipped_names: self.#skipped_names,)*
                                         }
                                     }
                                 }
@@ -409,7 +828,8 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                                         (
                                             (#(self.#current_field_names),*),
                                             #remainder_struct_name {
-                                                #(#other_field_names: self.#other_field_names),*
+                                                #(#other_field_names: self.#other_field_names,)*
+                                                #(#skipped_names: self.#skipped_names,)*
                                             },
                                         )
                                     }
@@ -420,6 +840,11 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
                     }
                 }
             }
+            for marker in label_markers {
+                state_conversions.push(quote::quote! {
+                    pub struct #marker;
+                });
+            }
             state_conversions
         }
         _ => todo!(),
@@ -430,15 +855,19 @@ pub fn state_filter_conversion(input: TokenStream) -> TokenStream {
     .into()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_original_conversion_combinations(
     state_conversions: &mut Vec<proc_macro2::TokenStream>,
+    is_allowed_split: &dyn Fn(&[Ident]) -> bool,
     original_generics: &Generics,
     combination_names: &HashMap<Vec<ConversionSort>, Ident>,
     remainder_names: &HashMap<Vec<ConversionSort>, Ident>,
     name: &Ident,
-    fields: &syn::Fields,
-    mut all_field_generics: Generics,
-) {
+    fields: &[&syn::Field],
+    skipped_names: &[&Ident],
+    skipped_generics: &Generics,
+) -> syn::Result<()> {
+    let mut all_field_generics = original_generics.clone();
     let fields: Vec<_> = fields
         .iter()
         .enumerate()
@@ -462,7 +891,7 @@ fn create_original_conversion_combinations(
             for (
                 current_field_names,
                 current_field_types,
-                current_field_generics,
+                _current_field_generics,
                 other_field_names,
                 other_field_types,
                 other_field_generics,
@@ -500,34 +929,30 @@ fn create_original_conversion_combinations(
                     other_field_generics,
                 )
             }) {
+                if !is_allowed_split(&current_field_names) {
+                    continue;
+                }
                 let r = current_field_types
                     .iter()
                     .chain(other_field_types.iter())
                     .cloned()
                     .sorted()
                     .collect::<Vec<_>>();
-                let combined_struct_name = combination_names.get(&r).expect(&format!(
-                    "1: expected a combined struct: {:#?}\nCOMBINATION NAMES: {:#?}",
-                    r, combination_names,
-                ));
+                if !combination_names.contains_key(&r) {
+                    return Err(internal_error(name, "combination", &r));
+                }
                 let remainder_struct_name = {
                     let mut other_field_types = other_field_types.clone();
                     other_field_types.sort();
                     remainder_names
                         .get(&other_field_types)
-                        .expect("expected a remainder struct")
+                        .ok_or_else(|| internal_error(name, "remainder", &other_field_types))?
                 };
-                let mut current_field_generic = Generics::default();
-                for current_generics in current_field_generics {
-                    current_field_generic =
-                        merge_generics(current_field_generic, &current_generics);
-                }
-                let current_field_generics = current_field_generic;
                 let mut other_field_generic = Generics::default();
                 for other_generics in other_field_generics {
                     other_field_generic = merge_generics(other_field_generic, &other_generics);
                 }
-                let other_field_generics = other_field_generic;
+                let other_field_generics = merge_generics(other_field_generic, skipped_generics);
                 let q = quote::quote! {
                     impl #all_field_generics state_validation::StateFilterInputConversion<(#(#current_field_types),*)> for #name #all_field_generics {
                         type Remainder = #remainder_struct_name #other_field_generics;
@@ -535,7 +960,8 @@ fn create_original_conversion_combinations(
                             (
                                 (#(self.#current_field_names),*),
                                 #remainder_struct_name {
-                                    #(#other_field_names: self.#other_field_names),*
+                                    #(#other_field_names: self.#other_field_names,)*
+                                    #(#skipped_names: self.#skipped_names,)*
                                 },
                             )
                         }
@@ -545,10 +971,334 @@ fn create_original_conversion_combinations(
             }
         }
     }
+    Ok(())
+}
+
+fn create_field_paths(
+    state_conversions: &mut Vec<proc_macro2::TokenStream>,
+    original_generics: &Generics,
+    name: &Ident,
+    fields: &[Vec<(Ident, ConversionSort, Generics)>],
+) {
+    let (impl_generics, ty_generics, where_clause) = original_generics.split_for_impl();
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|conversions| {
+            conversions
+                .iter()
+                .filter_map(|(field_name, field_type, _)| match &field_type.ty {
+                    ConversionType::Type(ty) => Some((field_name, ty)),
+                    ConversionType::Generic { .. } => None,
+                })
+                .unique_by(|(_, ty)| quote::quote!(#ty).to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let type_counts = fields
+        .iter()
+        .flatten()
+        .map(|(_, ty)| quote::quote!(#ty).to_string())
+        .counts();
+    for (field_name, ty) in fields.into_iter().flatten() {
+        // A type shared by more than one field cannot be traced back to a single field.
+        if type_counts[&quote::quote!(#ty).to_string()] > 1 {
+            continue;
+        }
+        let field_path = field_name.to_string();
+        state_conversions.push(quote::quote! {
+            impl #impl_generics state_validation::FieldPath<#ty> for #name #ty_generics #where_clause {
+                const FIELD_PATH: &'static str = #field_path;
+            }
+        });
+    }
+}
+
+fn create_introspection(
+    state_conversions: &mut Vec<proc_macro2::TokenStream>,
+    original_generics: &Generics,
+    name: &Ident,
+    fields: &[Vec<(Ident, ConversionSort, Generics)>],
+) {
+    let (impl_generics, ty_generics, where_clause) = original_generics.split_for_impl();
+    let conversions = fields
+        .iter()
+        .flatten()
+        .map(|(_, field_type, _)| field_type)
+        .unique_by(|field_type| quote::quote!(#field_type).to_string());
+    state_conversions.push(quote::quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Every type the fields of this struct can be split into.
+            pub const CONVERSIONS: &'static [&'static str] = &[#(::core::stringify!(#conversions)),*];
+        }
+    });
+}
+
+/// Names the struct generated for the fields converted into `types`, one type per field.
+fn create_alias(
+    alias: &Ident,
+    types: &[Type],
+    fields: &[Vec<(Ident, ConversionSort, Generics)>],
+    combination_names: &HashMap<Vec<ConversionSort>, Ident>,
+    combination_generics: &HashMap<Vec<ConversionSort>, Generics>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut used_fields = BTreeSet::new();
+    let mut field_types = Vec::with_capacity(types.len());
+    for ty in types {
+        let expected = quote::quote!(#ty).to_string();
+        let (i, field_type) = fields
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used_fields.contains(i))
+            .find_map(|(i, field)| {
+                field
+                    .iter()
+                    .find(|(_, field_type, _)| quote::quote!(#field_type).to_string() == expected)
+                    .map(|(_, field_type, _)| (i, field_type))
+            })
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ty,
+                    "expected a field convertible to this type, other than the fields already listed",
+                )
+            })?;
+        used_fields.insert(i);
+        field_types.push(field_type.clone());
+    }
+    field_types.sort();
+    let (Some(combined_struct_name), Some(generics)) = (
+        combination_names.get(&field_types),
+        combination_generics.get(&field_types),
+    ) else {
+        return Err(internal_error(alias, "combination", &field_types));
+    };
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let mut alias_generics = generics.clone();
+    for param in alias_generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(param) => param.bounds.clear(),
+            GenericParam::Lifetime(param) => param.bounds.clear(),
+            GenericParam::Const(_) => {}
+        }
+    }
+    alias_generics.where_clause = None;
+    Ok(quote::quote! {
+        pub type #alias #alias_generics = #combined_struct_name #ty_generics;
+    })
+}
+
+/// Generates a filter for each variant of an enum,
+/// which passes if the enum is that variant and outputs its fields.
+/// ```
+/// # use state_validation::{StateFilter, VariantFilters};
+/// # #[derive(Debug, PartialEq)]
+/// # struct UserID(usize);
+/// #[derive(VariantFilters)]
+/// enum Event {
+///     Login(UserID),
+///     Rename { user_id: UserID, username: String },
+///     Logout,
+/// }
+/// #[derive(VariantFilters)]
+/// enum Command {
+///     Logout,
+/// }
+/// assert_eq!(EventIsLogin::filter(&(), Event::Login(UserID(0))).unwrap(), UserID(0));
+/// assert!(EventIsRename::filter(&(), Event::Logout).is_err());
+/// assert!(EventIsLogout::filter(&(), Event::Logout).is_ok());
+/// assert!(CommandIsLogout::filter(&(), Command::Logout).is_ok());
+/// ```
+/// This generates the filters `EventIsLogin`, outputting a `UserID`,
+/// `EventIsRename`, outputting a `(UserID, String)`, and `EventIsLogout`, outputting `()`.
+/// They fail with the errors `EventNotLoginError`, `EventNotRenameError` and `EventNotLogoutError`.
+/// The names start with the name of the enum, so enums sharing a variant name can derive it side by side.
+#[proc_macro_derive(VariantFilters)]
+pub fn variant_filters(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let syn::Data::Enum(e) = &ast.data else {
+        return syn::Error::new_spanned(
+            &ast.ident,
+            "`VariantFilters` can only be derived for enums",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut state_generics = ast.generics.clone();
+    state_generics.params.insert(0, parse_quote!(State));
+    let (impl_generics, _, _) = state_generics.split_for_impl();
+    let mut variant_filters = Vec::with_capacity(e.variants.len());
+    for variant in e.variants.iter() {
+        let variant_name = &variant.ident;
+        let filter_name = quote::format_ident!("{}Is{}", name, variant_name);
+        let error_name = quote::format_ident!("{}Not{}Error", name, variant_name);
+        let error_message = format!("expected `{}::{}`", name, variant_name);
+        let field_types: Vec<_> = variant.fields.iter().map(|field| &field.ty).collect();
+        let field_bindings: Vec<_> = (0..field_types.len())
+            .map(|i| quote::format_ident!("field_{}", i))
+            .collect();
+        let pattern = match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let field_names = fields.named.iter().map(|field| &field.ident);
+                quote::quote!(#name::#variant_name { #(#field_names: #field_bindings),* })
+            }
+            syn::Fields::Unnamed(_) => quote::quote!(#name::#variant_name(#(#field_bindings),*)),
+            syn::Fields::Unit => quote::quote!(#name::#variant_name),
+        };
+        let (output_type, output) = if field_types.len() == 1 {
+            (
+                quote::quote!(#(#field_types)*),
+                quote::quote!(#(#field_bindings)*),
+            )
+        } else {
+            (
+                quote::quote!((#(#field_types),*)),
+                quote::quote!((#(#field_bindings),*)),
+            )
+        };
+        variant_filters.push(quote::quote! {
+            #vis struct #filter_name;
+            #[derive(Debug)]
+            #vis struct #error_name;
+            impl std::error::Error for #error_name {}
+            impl std::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(f, #error_message)
+                }
+            }
+            impl #impl_generics state_validation::StateFilter<State, #name #ty_generics> for #filter_name #where_clause {
+                type ValidOutput = #output_type;
+                type Error = #error_name;
+                fn filter(_state: &State, value: #name #ty_generics) -> Result<Self::ValidOutput, Self::Error> {
+                    match value {
+                        #pattern => Ok(#output),
+                        #[allow(unreachable_patterns)]
+                        _ => Err(#error_name),
+                    }
+                }
+            }
+        });
+    }
+    quote::quote! {
+        #(#variant_filters)*
+    }
+    .into()
 }
 
 // UTILITY //
 
+/// An invariant of the derive was broken, which is a bug in the derive rather than in the struct.
+fn internal_error(name: &Ident, kind: &str, field_types: &[ConversionSort]) -> syn::Error {
+    let field_types = field_types
+        .iter()
+        .map(|field_type| quote::quote!(#field_type).to_string())
+        .join(", ");
+    syn::Error::new(
+        name.span(),
+        format!(
+            "internal: could not find a generated {kind} for fields ({field_types}); please file a bug with this struct definition"
+        ),
+    )
+}
+
+/// Is this `#[conversion(field_paths)]`?
+fn is_field_paths(attr: &syn::Attribute) -> bool {
+    is_conversion_flag(attr, "field_paths")
+}
+
+/// Is this `#[conversion(skip)]`?
+fn is_skip(attr: &syn::Attribute) -> bool {
+    is_conversion_flag(attr, "skip")
+}
+
+/// Is this `#[conversion(introspect)]`?
+fn is_introspect(attr: &syn::Attribute) -> bool {
+    is_conversion_flag(attr, "introspect")
+}
+
+fn is_conversion_flag(attr: &syn::Attribute, flag: &str) -> bool {
+    attr.path().is_ident("conversion")
+        && attr.parse_args::<Ident>().is_ok_and(|ident| ident == flag)
+}
+
+/// The amount of fields, including extra fields, a struct may have unless raised with `max_fields`.
+const DEFAULT_MAX_FIELDS: usize = 6;
+
+/// Parses `#[conversion(max_fields = N)]`, or `None` if the attribute is not `max_fields`.
+fn parse_max_fields(attr: &syn::Attribute) -> Option<syn::Result<usize>> {
+    let is_max_fields = attr.path().is_ident("conversion")
+        && attr
+            .parse_args_with(|input: syn::parse::ParseStream| {
+                let ident: Ident = input.parse()?;
+                let _: proc_macro2::TokenStream = input.parse()?;
+                Ok(ident == "max_fields")
+            })
+            .unwrap_or(false);
+    is_max_fields.then(|| {
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let _: Ident = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            input.parse::<syn::LitInt>()?.base10_parse()
+        })
+    })
+}
+
+/// Parses `#[conversion(derive(A, B, ...))]`.
+fn parse_derives(attr: &syn::Attribute) -> Option<Vec<syn::Path>> {
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident: Ident = input.parse()?;
+        if ident != "derive" {
+            return Err(syn::Error::new(ident.span(), "expected `derive`"));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let derives =
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated(&content)?;
+        Ok(derives.into_iter().collect())
+    })
+    .ok()
+}
+
+/// Parses `#[conversion(name = "Alias", types(A, B, ...))]`.
+fn parse_alias(attr: &syn::Attribute) -> Option<(Ident, Vec<Type>)> {
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident: Ident = input.parse()?;
+        if ident != "name" {
+            return Err(syn::Error::new(ident.span(), "expected `name`"));
+        }
+        let _: syn::Token![=] = input.parse()?;
+        let alias: syn::LitStr = input.parse()?;
+        let _: syn::Token![,] = input.parse()?;
+        let ident: Ident = input.parse()?;
+        if ident != "types" {
+            return Err(syn::Error::new(ident.span(), "expected `types`"));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let types =
+            syn::punctuated::Punctuated::<Type, syn::Token![,]>::parse_terminated(&content)?;
+        Ok((alias.parse()?, types.into_iter().collect()))
+    })
+    .ok()
+}
+
+/// Parses `#[conversion(targets(A, B, ...))]`.
+fn parse_targets(attr: &syn::Attribute) -> Option<Vec<Type>> {
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident: Ident = input.parse()?;
+        if ident != "targets" {
+            return Err(syn::Error::new(ident.span(), "expected `targets`"));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let targets =
+            syn::punctuated::Punctuated::<Type, syn::Token![,]>::parse_terminated(&content)?;
+        Ok(targets.into_iter().collect())
+    })
+    .ok()
+}
+
 fn extract_generics_from_type(ty: &Type, original_generics: &Generics) -> Generics {
     let mut type_params = BTreeSet::new();
     let mut lifetime_params = BTreeSet::new();
@@ -683,6 +1433,39 @@ fn merge_generics(mut generics_a: Generics, generics_b: &Generics) -> Generics {
     generics_a
 }
 
+/// The `T` of an `Option<T>`, or `None` if the type is not an `Option`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.iter().exactly_one() {
+        Ok(GenericArgument::Type(inner)) => Some(inner),
+        _ => None,
+    }
+}
+
+/// The `Option<T>` type, spelled the same as `option`, with its `T` replaced by `inner`,
+/// or `None` if `option` is not an `Option`.
+fn with_option_inner_type(option: &Type, inner: &Type) -> Option<Type> {
+    option_inner_type(option)?;
+    let mut option = option.clone();
+    if let Type::Path(type_path) = &mut option
+        && let Some(segment) = type_path.path.segments.last_mut()
+        && let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments
+        && let Some(GenericArgument::Type(ty)) = arguments.args.first_mut()
+    {
+        *ty = inner.clone();
+    }
+    Some(option)
+}
+
 fn type_to_ident(ty: &Type) -> &Ident {
     match ty {
         Type::Path(type_path) => type_path
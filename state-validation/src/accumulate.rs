@@ -0,0 +1,61 @@
+/// Validates an input that arrives in chunks, such as a large upload,
+/// without holding the whole input at once.
+///
+/// Each chunk is [fed](AccumulatingFilter::feed) into the accumulator,
+/// which may reject the stream early.
+/// [`AccumulatingFilter::finish`] then validates the aggregate,
+/// and its output proves the whole stream was validated.
+/// ```
+/// # use state_validation::AccumulatingFilter;
+/// # #[derive(Debug)]
+/// # struct TooLargeError;
+/// # impl std::error::Error for TooLargeError {}
+/// # impl std::fmt::Display for TooLargeError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "upload too large")
+/// #     }
+/// # }
+/// struct MaxUploadSize(usize);
+/// struct UploadSize {
+///     total: usize,
+/// }
+/// impl AccumulatingFilter<MaxUploadSize> for UploadSize {
+///     type Chunk = Vec<u8>;
+///     type ValidOutput = usize;
+///     type Error = TooLargeError;
+///     fn feed(&mut self, state: &MaxUploadSize, chunk: Vec<u8>) -> Result<(), Self::Error> {
+///         self.total += chunk.len();
+///         if self.total > state.0 { Err(TooLargeError) } else { Ok(()) }
+///     }
+///     fn finish(self, _state: &MaxUploadSize) -> Result<Self::ValidOutput, Self::Error> {
+///         Ok(self.total)
+///     }
+/// }
+///
+/// let state = MaxUploadSize(8);
+/// let size = UploadSize { total: 0 }
+///     .accumulate(&state, [vec![1, 2, 3], vec![4, 5]])
+///     .unwrap();
+/// assert_eq!(size, 5);
+/// assert!(UploadSize { total: 0 }.accumulate(&state, [vec![0; 5], vec![0; 5]]).is_err());
+/// ```
+pub trait AccumulatingFilter<State>: Sized {
+    type Chunk;
+    type ValidOutput;
+    type Error: std::error::Error;
+    /// Adds the chunk to the accumulator, failing if the stream can already be rejected.
+    fn feed(&mut self, state: &State, chunk: Self::Chunk) -> Result<(), Self::Error>;
+    /// Validates the aggregate of every chunk fed.
+    fn finish(self, state: &State) -> Result<Self::ValidOutput, Self::Error>;
+    /// Feeds every chunk, and then finishes, stopping at the first error.
+    fn accumulate(
+        mut self,
+        state: &State,
+        chunks: impl IntoIterator<Item = Self::Chunk>,
+    ) -> Result<Self::ValidOutput, Self::Error> {
+        for chunk in chunks {
+            self.feed(state, chunk)?;
+        }
+        self.finish(state)
+    }
+}
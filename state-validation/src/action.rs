@@ -1,4 +1,4 @@
-use crate::StateFilter;
+use crate::{StateFilter, StateFilterBorrow};
 
 pub trait ValidAction<State, Input> {
     type Filter: StateFilter<State, Input>;
@@ -9,3 +9,153 @@ pub trait ValidAction<State, Input> {
         valid: <Self::Filter as StateFilter<State, Input>>::ValidOutput,
     ) -> Self::Output;
 }
+
+/// A [`ValidAction`] which may decide not to proceed once it has the valid output in hand.
+///
+/// Aborting returns the state untouched, as if nothing happened.
+/// ```
+/// # use state_validation::{AbortableAction, StateFilter, Validator};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = u32;
+///     type Error = ZeroError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+///     }
+/// }
+/// struct Withdraw;
+/// impl AbortableAction<u32, u32> for Withdraw {
+///     type Filter = NonZero;
+///     type Output = u32;
+///     fn with_valid_input(self, balance: u32, amount: u32) -> Result<Self::Output, u32> {
+///         // Not enough funds, so the balance is given back untouched.
+///         balance.checked_sub(amount).ok_or(balance)
+///     }
+/// }
+///
+/// let validator = Validator::<_, _, NonZero>::try_new(10, 4).unwrap();
+/// assert_eq!(validator.execute_abortable(Withdraw), Ok(6));
+/// ```
+pub trait AbortableAction<State, Input> {
+    type Filter: StateFilter<State, Input>;
+    type Output;
+    fn with_valid_input(
+        self,
+        state: State,
+        valid: <Self::Filter as StateFilter<State, Input>>::ValidOutput,
+    ) -> Result<Self::Output, State>;
+}
+
+/// A [`ValidAction`] which only reads the state and valid output, executed by
+/// [`Validator::execute_borrow_valid`](crate::Validator::execute_borrow_valid),
+/// so the validator keeps both for further use.
+pub trait ValidActionBorrowValid<State, Input> {
+    type Filter: StateFilter<State, Input>;
+    type Output;
+    fn with_valid_input(
+        self,
+        state: &State,
+        valid: &<Self::Filter as StateFilter<State, Input>>::ValidOutput,
+    ) -> Self::Output;
+}
+
+/// A [`ValidAction`] which mutates the state in place, executed by a [`ValidatorMut`](crate::ValidatorMut),
+/// for state that lives inside a larger structure.
+pub trait MutAction<State, Input> {
+    type Filter: StateFilter<State, Input>;
+    type Output;
+    fn with_valid_input(
+        self,
+        state: &mut State,
+        valid: <Self::Filter as StateFilter<State, Input>>::ValidOutput,
+    ) -> Self::Output;
+}
+
+/// An action which only reads the state, emitting events to be applied elsewhere,
+/// executed by [`ValidatorRef::emit`](crate::ValidatorRef::emit).
+pub trait EventAction<State, Input> {
+    type Filter: StateFilterBorrow<State, Input>;
+    type Event;
+    fn emit<'s>(
+        self,
+        state: &'s State,
+        valid: <Self::Filter as StateFilterBorrow<State, Input>>::ValidOutput<'s>,
+    ) -> Vec<Self::Event>;
+}
+
+/// Code run around an action by [`Validator::execute_hooked`](crate::Validator::execute_hooked),
+/// such as audit logging or metrics.
+pub trait ExecuteHooks<State, ValidOutput, Output> {
+    /// Called before the action takes ownership of the state.
+    fn before(&mut self, _state: &State, _valid: &ValidOutput) {}
+    fn after(&mut self, _output: &Output) {}
+}
+
+/// Requires the filter of an action to be the filter of the [`Validator`](crate::Validator) executing it,
+/// reporting a mismatch clearly instead of as an unresolved associated type.
+/// ```compile_fail
+/// # use state_validation::{StateFilter, ValidAction, Validator};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = u32;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value == 0 { Err(InvalidError) } else { Ok(value) }
+///     }
+/// }
+/// struct Even;
+/// impl<State> StateFilter<State, u32> for Even {
+///     type ValidOutput = u32;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value % 2 == 0 { Ok(value) } else { Err(InvalidError) }
+///     }
+/// }
+/// struct Halve;
+/// impl ValidAction<(), u32> for Halve {
+///     type Filter = Even;
+///     type Output = u32;
+///     fn with_valid_input(self, _state: (), value: u32) -> Self::Output {
+///         value / 2
+///     }
+/// }
+///
+/// // error: this action's `Filter` (`Even`) does not match the validator's filter (`NonZero`)
+/// Validator::<_, _, NonZero>::try_new((), 4).unwrap().execute(Halve);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "this action's `Filter` (`{Self}`) does not match the validator's filter (`{Filter}`)",
+    label = "expected `{Filter}`",
+    note = "the action's `Filter` and the validator's filter must be identical"
+)]
+pub trait SameFilter<State, Input, Filter: StateFilter<State, Input>>:
+    StateFilter<State, Input>
+{
+    fn same_output(valid: Filter::ValidOutput) -> Self::ValidOutput;
+    fn same_output_ref(valid: &Filter::ValidOutput) -> &Self::ValidOutput;
+}
+
+impl<State, Input, Filter: StateFilter<State, Input>> SameFilter<State, Input, Filter> for Filter {
+    fn same_output(valid: Filter::ValidOutput) -> Self::ValidOutput {
+        valid
+    }
+    fn same_output_ref(valid: &Filter::ValidOutput) -> &Self::ValidOutput {
+        valid
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{StateFilter, Validator};
+
+impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Filter>
+where
+    Filter::Error: Send + Sync + 'static,
+{
+    /// Same as [`Validator::try_new`], but the state is dropped on failure
+    /// and the error is converted into an [`anyhow::Error`], so `?` works in application code.
+    /// ```
+    /// # use state_validation::{StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// struct NonZero;
+    /// impl<State> StateFilter<State, u32> for NonZero {
+    ///     type ValidOutput = u32;
+    ///     type Error = ZeroError;
+    ///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// fn double(value: u32) -> anyhow::Result<u32> {
+    ///     let validator = Validator::<_, _, NonZero>::try_new_anyhow((), value)?;
+    ///     Ok(validator.valid_output() * 2)
+    /// }
+    /// assert_eq!(double(2).unwrap(), 4);
+    /// let error = double(0).unwrap_err();
+    /// assert!(error.is::<ZeroError>());
+    /// assert_eq!(error.to_string(), "zero");
+    /// ```
+    pub fn try_new_anyhow(state: State, input: Input) -> anyhow::Result<Self> {
+        Validator::try_new(state, input).map_err(|e| anyhow::Error::new(e.error))
+    }
+}
@@ -0,0 +1,115 @@
+/// A [`StateFilter`](crate::StateFilter) which must wait on something, such as a remote service, to validate.
+pub trait StateFilterAsync<State, Input> {
+    type ValidOutput;
+    type Error: std::error::Error;
+    fn filter(
+        state: &State,
+        value: Input,
+    ) -> impl Future<Output = Result<Self::ValidOutput, Self::Error>>;
+}
+
+/// A [`Validator`](crate::Validator) whose filter is a [`StateFilterAsync`].
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{AsyncInputError, StateFilterAsync, ValidatorAsync};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # struct FetchError;
+/// # impl std::error::Error for FetchError {}
+/// # impl std::fmt::Display for FetchError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "failed to fetch")
+/// #     }
+/// # }
+/// # fn block_on<F: Future>(future: F) -> F::Output {
+/// #     let mut future = std::pin::pin!(future);
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// struct UserStorage(HashMap<u32, String>);
+/// struct UserExists;
+/// impl StateFilterAsync<UserStorage, u32> for UserExists {
+///     type ValidOutput = String;
+///     type Error = UserDoesNotExistError;
+///     async fn filter(state: &UserStorage, user_id: u32) -> Result<String, Self::Error> {
+///         state.0.get(&user_id).cloned().ok_or(UserDoesNotExistError)
+///     }
+/// }
+/// async fn fetch_user_id(found: bool) -> Result<u32, FetchError> {
+///     if found { Ok(0) } else { Err(FetchError) }
+/// }
+///
+/// let users = || UserStorage(HashMap::from([(0, "admin".to_string())]));
+/// let validator = block_on(ValidatorAsync::<_, _, UserExists>::try_new_future(
+///     users(),
+///     fetch_user_id(true),
+/// ))
+/// .unwrap();
+/// assert_eq!(validator.valid_output(), "admin");
+/// let result = block_on(ValidatorAsync::<_, _, UserExists>::try_new_future(
+///     users(),
+///     fetch_user_id(false),
+/// ));
+/// assert!(matches!(result, Err(AsyncInputError::Input(FetchError))));
+/// ```
+pub struct ValidatorAsync<State, Input, Filter: StateFilterAsync<State, Input>> {
+    state: State,
+    value: Filter::ValidOutput,
+    _p: std::marker::PhantomData<fn() -> Input>,
+}
+
+impl<State, Input, Filter: StateFilterAsync<State, Input>> ValidatorAsync<State, Input, Filter> {
+    pub async fn try_new(state: State, input: Input) -> Result<Self, Filter::Error> {
+        let value = Filter::filter(&state, input).await?;
+        Ok(ValidatorAsync {
+            state,
+            value,
+            _p: std::marker::PhantomData,
+        })
+    }
+    /// Awaits the input, then validates it.
+    pub async fn try_new_future<E: std::error::Error>(
+        state: State,
+        input: impl Future<Output = Result<Input, E>>,
+    ) -> Result<Self, AsyncInputError<E, Filter::Error>> {
+        let input = input.await.map_err(AsyncInputError::Input)?;
+        Self::try_new(state, input)
+            .await
+            .map_err(AsyncInputError::Filter)
+    }
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+    pub fn valid_output(&self) -> &Filter::ValidOutput {
+        &self.value
+    }
+    /// Executes an action with the state and valid output.
+    pub fn execute<Output>(
+        self,
+        action: impl FnOnce(State, Filter::ValidOutput) -> Output,
+    ) -> Output {
+        action(self.state, self.value)
+    }
+}
+
+/// The error of [`ValidatorAsync::try_new_future`].
+#[derive(thiserror::Error, Debug)]
+pub enum AsyncInputError<InputError: std::error::Error, FilterError: std::error::Error> {
+    /// The input could not be produced.
+    #[error("failed to produce the input: {0}")]
+    Input(InputError),
+    /// The input was produced, but did not pass the filter.
+    #[error(transparent)]
+    Filter(FilterError),
+}
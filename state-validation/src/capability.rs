@@ -0,0 +1,75 @@
+use crate::StateFilter;
+
+/// Proof that a permission to perform `A` was checked.
+///
+/// It can only be created by [`RequiresCapability`],
+/// so an action taking a `Capability<A>` as part of its valid input cannot run unauthorized.
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{Capability, GrantsCapability, RequiresCapability, StateFilter, ValidAction, Validator};
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// struct UserID(usize);
+/// struct UserStorage {
+///     admins: HashSet<UserID>,
+/// }
+/// struct RemoveAdmin;
+/// impl GrantsCapability<UserID, RemoveAdmin> for UserStorage {
+///     fn grants(&self, user_id: &UserID) -> bool {
+///         self.admins.contains(user_id)
+///     }
+/// }
+/// impl ValidAction<UserStorage, UserID> for RemoveAdmin {
+///     type Filter = RequiresCapability<RemoveAdmin>;
+///     type Output = UserStorage;
+///     fn with_valid_input(
+///         self,
+///         state: UserStorage,
+///         _capability: Capability<RemoveAdmin>,
+///     ) -> Self::Output {
+///         state
+///     }
+/// }
+///
+/// let users = UserStorage { admins: HashSet::from([UserID(0)]) };
+/// let users = Validator::try_new(users, UserID(0)).unwrap().execute(RemoveAdmin);
+/// assert!(Validator::<_, _, RequiresCapability<RemoveAdmin>>::try_new(users, UserID(1)).is_err());
+/// ```
+pub struct Capability<A>(std::marker::PhantomData<A>);
+
+impl<A> Clone for Capability<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<A> Copy for Capability<A> {}
+
+/// State which knows whether a subject, such as a user, may perform `A`.
+pub trait GrantsCapability<Subject, A> {
+    fn grants(&self, subject: &Subject) -> bool;
+}
+
+/// Passes if the state grants the subject the capability to perform `A`,
+/// outputting the [`Capability`] as proof.
+pub struct RequiresCapability<A>(std::marker::PhantomData<A>);
+
+impl<State: GrantsCapability<Subject, A>, Subject, A> StateFilter<State, Subject>
+    for RequiresCapability<A>
+{
+    type ValidOutput = Capability<A>;
+    type Error = MissingCapabilityError;
+    fn filter(state: &State, value: Subject) -> Result<Self::ValidOutput, Self::Error> {
+        if state.grants(&value) {
+            Ok(Capability(std::marker::PhantomData))
+        } else {
+            Err(MissingCapabilityError {
+                capability: std::any::type_name::<A>(),
+            })
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("missing the capability to perform `{capability}`")]
+pub struct MissingCapabilityError {
+    pub capability: &'static str,
+}
@@ -0,0 +1,730 @@
+use crate::{StateFilter, StateFilterRef, StateFilterTwoChainError};
+
+/// Runs `A`, then runs `B` on the output of `A`,
+/// keeping the output of both.
+/// ```
+/// # use state_validation::{StateFilter, WithIntermediate};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// struct Parse;
+/// impl<State> StateFilter<State, &str> for Parse {
+///     type ValidOutput = u32;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: &str) -> Result<Self::ValidOutput, Self::Error> {
+///         value.parse().map_err(|_| InvalidError)
+///     }
+/// }
+/// struct Double;
+/// impl<State> StateFilter<State, u32> for Double {
+///     type ValidOutput = u64;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         Ok(value as u64 * 2)
+///     }
+/// }
+///
+/// let (parsed, doubled) = WithIntermediate::<Parse, Double>::filter(&(), "21").unwrap();
+/// assert_eq!(parsed, 21);
+/// assert_eq!(doubled, 42);
+/// ```
+pub struct WithIntermediate<A, B>(std::marker::PhantomData<(A, B)>);
+
+impl<State, Input, A: StateFilter<State, Input>, B: StateFilter<State, A::ValidOutput>>
+    StateFilter<State, Input> for WithIntermediate<A, B>
+where
+    A::ValidOutput: Clone,
+{
+    type ValidOutput = (A::ValidOutput, B::ValidOutput);
+    type Error = StateFilterTwoChainError<A::Error, B::Error>;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let intermediate = A::filter(state, value).map_err(StateFilterTwoChainError::Filter0)?;
+        let output =
+            B::filter(state, intermediate.clone()).map_err(StateFilterTwoChainError::Filter1)?;
+        Ok((intermediate, output))
+    }
+}
+
+/// Converts the error of `F` into `E`,
+/// so a chain of filters can share a single error type.
+/// ```
+/// # use state_validation::{MapErr, StateFilter};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// # struct NonZero;
+/// # impl<State> StateFilter<State, u32> for NonZero {
+/// #     type ValidOutput = u32;
+/// #     type Error = ZeroError;
+/// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+/// #     }
+/// # }
+/// #[derive(Debug, PartialEq)]
+/// struct RequestError(&'static str);
+/// # impl std::error::Error for RequestError {}
+/// # impl std::fmt::Display for RequestError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "{}", self.0)
+/// #     }
+/// # }
+/// impl From<ZeroError> for RequestError {
+///     fn from(_: ZeroError) -> Self {
+///         RequestError("amount must not be zero")
+///     }
+/// }
+///
+/// assert_eq!(
+///     MapErr::<NonZero, RequestError>::filter(&(), 0).unwrap_err(),
+///     RequestError("amount must not be zero"),
+/// );
+/// ```
+pub struct MapErr<F, E>(std::marker::PhantomData<(F, E)>);
+
+impl<State, Input, F: StateFilter<State, Input>, E: std::error::Error + From<F::Error>>
+    StateFilter<State, Input> for MapErr<F, E>
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = E;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, value).map_err(E::from)
+    }
+}
+
+/// Runs `F` on the input, attaching the context `C` to the error if it fails,
+/// such as the ID of the request being validated.
+///
+/// The context is passed in alongside the input, and passed along with the output.
+/// ```
+/// # use state_validation::{StateFilter, WithContext};
+/// # #[derive(Debug)]
+/// # struct OddError;
+/// # impl std::error::Error for OddError {}
+/// # impl std::fmt::Display for OddError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "odd")
+/// #     }
+/// # }
+/// struct Even;
+/// impl<State> StateFilter<State, u32> for Even {
+///     type ValidOutput = u32;
+///     type Error = OddError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value % 2 == 0 { Ok(value) } else { Err(OddError) }
+///     }
+/// }
+///
+/// let error = WithContext::<Even, &str>::filter(&(), (3, "request 7")).unwrap_err();
+/// assert_eq!(error.to_string(), "request 7: odd");
+/// ```
+pub struct WithContext<F, C>(std::marker::PhantomData<(F, C)>);
+
+impl<State, Input, F: StateFilter<State, Input>, C: std::fmt::Debug + std::fmt::Display>
+    StateFilter<State, (Input, C)> for WithContext<F, C>
+{
+    type ValidOutput = (F::ValidOutput, C);
+    type Error = ContextualError<C, F::Error>;
+    fn filter(
+        state: &State,
+        (value, context): (Input, C),
+    ) -> Result<Self::ValidOutput, Self::Error> {
+        match F::filter(state, value) {
+            Ok(output) => Ok((output, context)),
+            Err(error) => Err(ContextualError { context, error }),
+        }
+    }
+}
+
+/// An error with the context it occurred in.
+#[derive(thiserror::Error, Debug)]
+#[error("{context}: {error}")]
+pub struct ContextualError<C: std::fmt::Debug + std::fmt::Display, E: std::error::Error> {
+    pub context: C,
+    pub error: E,
+}
+
+/// Decides whether [`SkipIf`] skips its filter.
+pub trait SkipPredicate<State, Input> {
+    fn should_skip(state: &State, value: &Input) -> bool;
+}
+
+/// Runs `F`, unless `P` decides to skip it, in which case the input is passed through.
+///
+/// Since the input is passed through in place of the output of `F`,
+/// it must convert into that output.
+/// ```
+/// # use state_validation::{SkipIf, SkipPredicate, StateFilter};
+/// # #[derive(Debug)]
+/// # struct NotAdminError;
+/// # impl std::error::Error for NotAdminError {}
+/// # impl std::fmt::Display for NotAdminError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "not an admin")
+/// #     }
+/// # }
+/// struct User {
+///     name: String,
+///     superuser: bool,
+/// }
+/// struct IsAdmin;
+/// impl<State> StateFilter<State, User> for IsAdmin {
+///     type ValidOutput = User;
+///     type Error = NotAdminError;
+///     fn filter(_state: &State, user: User) -> Result<Self::ValidOutput, Self::Error> {
+///         if user.name == "admin" { Ok(user) } else { Err(NotAdminError) }
+///     }
+/// }
+/// struct IsSuperuser;
+/// impl<State> SkipPredicate<State, User> for IsSuperuser {
+///     fn should_skip(_state: &State, user: &User) -> bool {
+///         user.superuser
+///     }
+/// }
+///
+/// let root = User { name: "root".to_string(), superuser: true };
+/// assert!(SkipIf::<IsSuperuser, IsAdmin>::filter(&(), root).is_ok());
+/// let guest = User { name: "guest".to_string(), superuser: false };
+/// assert!(SkipIf::<IsSuperuser, IsAdmin>::filter(&(), guest).is_err());
+/// ```
+pub struct SkipIf<P, F>(std::marker::PhantomData<(P, F)>);
+
+impl<
+    State,
+    Input: Into<F::ValidOutput>,
+    P: SkipPredicate<State, Input>,
+    F: StateFilter<State, Input>,
+> StateFilter<State, Input> for SkipIf<P, F>
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = F::Error;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        if P::should_skip(state, &value) {
+            Ok(value.into())
+        } else {
+            F::filter(state, value)
+        }
+    }
+}
+
+/// Runs `F`, outputting the default of its output if it fails.
+///
+/// The error of `F` is swallowed,
+/// so only use this where a failed validation is not worth reporting,
+/// such as an optional preference that falls back to the system's default.
+/// ```
+/// # use state_validation::{OrDefault, StateFilter};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = u32;
+///     type Error = ZeroError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+///     }
+/// }
+///
+/// assert_eq!(OrDefault::<NonZero>::filter(&(), 5).unwrap(), 5);
+/// assert_eq!(OrDefault::<NonZero>::filter(&(), 0).unwrap(), 0);
+/// ```
+pub struct OrDefault<F>(std::marker::PhantomData<F>);
+
+impl<State, Input, F: StateFilter<State, Input>> StateFilter<State, Input> for OrDefault<F>
+where
+    F::ValidOutput: Default,
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = std::convert::Infallible;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        Ok(F::filter(state, value).unwrap_or_default())
+    }
+}
+
+/// Classifies the errors of a filter wrapped in [`Lenient`].
+pub trait NonFatal<E> {
+    /// Should the error be treated as advisory, passing the input through?
+    fn is_non_fatal(error: &E) -> bool;
+    /// Called with every non-fatal error, such as to log it.
+    fn report(_error: &E) {}
+}
+
+/// Runs `F`, passing the input through if it fails with an error `P` deems non-fatal.
+///
+/// Since the input is passed through in place of the output of `F`,
+/// it must convert into that output.
+/// ```
+/// # use state_validation::{Lenient, NonFatal, StateFilter};
+/// #[derive(Debug)]
+/// enum PasswordError {
+///     Weak,
+///     Empty,
+/// }
+/// # impl std::error::Error for PasswordError {}
+/// # impl std::fmt::Display for PasswordError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "{self:?}")
+/// #     }
+/// # }
+/// struct StrongPassword;
+/// impl<State> StateFilter<State, String> for StrongPassword {
+///     type ValidOutput = String;
+///     type Error = PasswordError;
+///     fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+///         if value.is_empty() {
+///             Err(PasswordError::Empty)
+///         } else if value.len() < 12 {
+///             Err(PasswordError::Weak)
+///         } else {
+///             Ok(value)
+///         }
+///     }
+/// }
+/// thread_local! {
+///     static WARNINGS: std::cell::RefCell<Vec<String>> = Default::default();
+/// }
+/// struct WeakIsAdvisory;
+/// impl NonFatal<PasswordError> for WeakIsAdvisory {
+///     fn is_non_fatal(error: &PasswordError) -> bool {
+///         matches!(error, PasswordError::Weak)
+///     }
+///     fn report(error: &PasswordError) {
+///         WARNINGS.with_borrow_mut(|warnings| warnings.push(format!("warning: {error}")));
+///     }
+/// }
+///
+/// assert!(Lenient::<StrongPassword, WeakIsAdvisory>::filter(&(), "hunter2".to_string()).is_ok());
+/// assert!(Lenient::<StrongPassword, WeakIsAdvisory>::filter(&(), String::new()).is_err());
+/// // Only the non-fatal error was reported.
+/// assert_eq!(WARNINGS.take(), ["warning: Weak"]);
+/// ```
+pub struct Lenient<F, P>(std::marker::PhantomData<(F, P)>);
+
+impl<
+    State,
+    Input: Clone + Into<F::ValidOutput>,
+    F: StateFilter<State, Input>,
+    P: NonFatal<F::Error>,
+> StateFilter<State, Input> for Lenient<F, P>
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = F::Error;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        match F::filter(state, value.clone()) {
+            Ok(output) => Ok(output),
+            Err(error) if P::is_non_fatal(&error) => {
+                P::report(&error);
+                Ok(value.into())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// A wrapper around a single value, such as `AdminUser(User)`.
+pub trait Newtype {
+    type Inner;
+    fn into_inner(self) -> Self::Inner;
+    fn from_inner(inner: Self::Inner) -> Self;
+}
+
+/// Runs `F`, a filter over the inner value of a [`Newtype`], on the newtype,
+/// unwrapping it before and rewrapping it after.
+/// ```
+/// # use state_validation::{Newtype, StateFilter, Transparent};
+/// # #[derive(Debug)]
+/// # struct BlankError;
+/// # impl std::error::Error for BlankError {}
+/// # impl std::fmt::Display for BlankError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "blank")
+/// #     }
+/// # }
+/// struct Trimmed;
+/// impl<State> StateFilter<State, String> for Trimmed {
+///     type ValidOutput = String;
+///     type Error = BlankError;
+///     fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+///         let value = value.trim();
+///         if value.is_empty() { Err(BlankError) } else { Ok(value.to_string()) }
+///     }
+/// }
+/// struct Username(String);
+/// impl Newtype for Username {
+///     type Inner = String;
+///     fn into_inner(self) -> String {
+///         self.0
+///     }
+///     fn from_inner(inner: String) -> Self {
+///         Username(inner)
+///     }
+/// }
+///
+/// let username = Transparent::<Trimmed>::filter(&(), Username(" admin ".to_string())).unwrap();
+/// assert_eq!(username.0, "admin");
+/// ```
+pub struct Transparent<F>(std::marker::PhantomData<F>);
+
+impl<State, W: Newtype, F: StateFilter<State, W::Inner, ValidOutput = W::Inner>>
+    StateFilter<State, W> for Transparent<F>
+{
+    type ValidOutput = W;
+    type Error = F::Error;
+    fn filter(state: &State, value: W) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, value.into_inner()).map(W::from_inner)
+    }
+}
+
+/// Runs the chain `A`, and if it fails, runs the chain `B` on a clone of the input instead,
+/// such as to authorize with either an API key or a session cookie.
+/// ```
+/// # use state_validation::{Either, FallbackChain, StateFilter};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// struct ApiKey;
+/// impl<State> StateFilter<State, String> for ApiKey {
+///     type ValidOutput = String;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+///         value.strip_prefix("key:").map(str::to_string).ok_or(InvalidError)
+///     }
+/// }
+/// struct Session;
+/// impl<State> StateFilter<State, String> for Session {
+///     type ValidOutput = u32;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+///         value.strip_prefix("session:").and_then(|id| id.parse().ok()).ok_or(InvalidError)
+///     }
+/// }
+///
+/// type Authorize = FallbackChain<ApiKey, Session>;
+/// assert!(matches!(Authorize::filter(&(), "key:abc".to_string()), Ok(Either::Left(_))));
+/// assert!(matches!(Authorize::filter(&(), "session:7".to_string()), Ok(Either::Right(7))));
+/// assert!(Authorize::filter(&(), "anonymous".to_string()).is_err());
+/// ```
+pub struct FallbackChain<A, B>(std::marker::PhantomData<(A, B)>);
+
+impl<State, Input: Clone, A: StateFilter<State, Input>, B: StateFilter<State, Input>>
+    StateFilter<State, Input> for FallbackChain<A, B>
+{
+    type ValidOutput = Either<A::ValidOutput, B::ValidOutput>;
+    type Error = FallbackChainError<A::Error, B::Error>;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        match A::filter(state, value.clone()) {
+            Ok(output) => Ok(Either::Left(output)),
+            Err(primary) => match B::filter(state, value) {
+                Ok(output) => Ok(Either::Right(output)),
+                Err(fallback) => Err(FallbackChainError { primary, fallback }),
+            },
+        }
+    }
+}
+
+/// The output of one of two filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("{primary}, and the fallback failed: {fallback}")]
+pub struct FallbackChainError<A: std::error::Error, B: std::error::Error> {
+    pub primary: A,
+    pub fallback: B,
+}
+
+/// Tries each filter of the tuple in order, outputting the first success,
+/// such as to accept an ID in either its current or legacy format.
+///
+/// Unlike [`FallbackChain`], every filter outputs the same type, so the output is not an [`Either`].
+/// ```
+/// # use state_validation::{NormalizeAny, StateFilter};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// #[derive(Debug, PartialEq)]
+/// struct UserID(u64);
+/// struct HexID;
+/// impl<State> StateFilter<State, &str> for HexID {
+///     type ValidOutput = UserID;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: &str) -> Result<UserID, Self::Error> {
+///         let hex = value.strip_prefix("0x").ok_or(InvalidError)?;
+///         u64::from_str_radix(hex, 16).map(UserID).map_err(|_| InvalidError)
+///     }
+/// }
+/// struct LegacyID;
+/// impl<State> StateFilter<State, &str> for LegacyID {
+///     type ValidOutput = UserID;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: &str) -> Result<UserID, Self::Error> {
+///         value.parse().map(UserID).map_err(|_| InvalidError)
+///     }
+/// }
+///
+/// type ParseUserID = NormalizeAny<(HexID, LegacyID)>;
+/// assert_eq!(ParseUserID::filter(&(), "0xff").unwrap(), UserID(255));
+/// assert_eq!(ParseUserID::filter(&(), "255").unwrap(), UserID(255));
+/// let error = ParseUserID::filter(&(), "admin").unwrap_err();
+/// assert_eq!(error.errors.len(), 2);
+/// ```
+pub struct NormalizeAny<Filters>(std::marker::PhantomData<Filters>);
+
+macro_rules! impl_normalize_any {
+    ($($F:ident),*) => {
+        impl<State, Input: Clone, Output, $($F: StateFilter<State, Input, ValidOutput = Output, Error: 'static>),*>
+            StateFilter<State, Input> for NormalizeAny<($($F,)*)>
+        {
+            type ValidOutput = Output;
+            type Error = NormalizeAnyError;
+            fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+                let mut errors: Vec<Box<dyn std::error::Error>> = Vec::new();
+                $(
+                    match $F::filter(state, value.clone()) {
+                        Ok(output) => return Ok(output),
+                        Err(error) => errors.push(Box::new(error)),
+                    }
+                )*
+                Err(NormalizeAnyError { errors })
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_normalize_any, 1, 8, F);
+
+/// Same as [`NormalizeAny`], but also outputs the index of the filter which passed,
+/// so an action can tell which alternative granted access.
+/// ```
+/// # use state_validation::{StateFilter, Tagged};
+/// # #[derive(Debug)]
+/// # struct DeniedError;
+/// # impl std::error::Error for DeniedError {}
+/// # impl std::fmt::Display for DeniedError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "denied")
+/// #     }
+/// # }
+/// struct Document {
+///     owner: u32,
+/// }
+/// struct IsAdmin;
+/// impl StateFilter<Document, u32> for IsAdmin {
+///     type ValidOutput = u32;
+///     type Error = DeniedError;
+///     fn filter(_document: &Document, user: u32) -> Result<u32, Self::Error> {
+///         if user == 0 { Ok(user) } else { Err(DeniedError) }
+///     }
+/// }
+/// struct IsOwner;
+/// impl StateFilter<Document, u32> for IsOwner {
+///     type ValidOutput = u32;
+///     type Error = DeniedError;
+///     fn filter(document: &Document, user: u32) -> Result<u32, Self::Error> {
+///         if user == document.owner { Ok(user) } else { Err(DeniedError) }
+///     }
+/// }
+///
+/// type CanEdit = Tagged<(IsAdmin, IsOwner)>;
+/// let document = Document { owner: 7 };
+/// assert_eq!(CanEdit::filter(&document, 0).unwrap(), (0, 0));
+/// let (branch, _user) = CanEdit::filter(&document, 7).unwrap();
+/// assert_eq!(branch, 1);
+/// assert!(CanEdit::branch_name(branch).unwrap().ends_with("IsOwner"));
+/// assert!(CanEdit::filter(&document, 8).is_err());
+/// ```
+pub struct Tagged<Filters>(std::marker::PhantomData<Filters>);
+
+macro_rules! impl_tagged {
+    ($($F:ident),*) => {
+        impl<State, Input: Clone, Output, $($F: StateFilter<State, Input, ValidOutput = Output, Error: 'static>),*>
+            StateFilter<State, Input> for Tagged<($($F,)*)>
+        {
+            type ValidOutput = (usize, Output);
+            type Error = NormalizeAnyError;
+            fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+                let mut errors: Vec<Box<dyn std::error::Error>> = Vec::new();
+                $(
+                    match $F::filter(state, value.clone()) {
+                        Ok(output) => return Ok((errors.len(), output)),
+                        Err(error) => errors.push(Box::new(error)),
+                    }
+                )*
+                Err(NormalizeAnyError { errors })
+            }
+        }
+        impl<$($F),*> Tagged<($($F,)*)> {
+            /// The type name of the filter at the index output by [`Tagged`].
+            pub fn branch_name(index: usize) -> Option<&'static str> {
+                [$(std::any::type_name::<$F>()),*].get(index).copied()
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_tagged, 1, 8, F);
+
+/// The errors of every filter of a [`NormalizeAny`] or [`Tagged`], in order.
+#[derive(thiserror::Error, Debug)]
+#[error("no filter passed: {}", errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+pub struct NormalizeAnyError {
+    pub errors: Vec<Box<dyn std::error::Error>>,
+}
+
+/// A condition on the whole input, deciding whether [`RequiredIf`] runs its filter.
+pub trait InputCondition<State, Input> {
+    fn holds(state: &State, value: &Input) -> bool;
+}
+
+/// Projects a field out of the input for [`RequiredIf`].
+pub trait Project<Input> {
+    type Field;
+    fn project(value: &Input) -> &Self::Field;
+}
+
+/// Runs `F` on the field projected by `P` only if `C` holds, passing the input through.
+///
+/// Captures conditional requirements, such as a state being required only in some countries.
+/// ```
+/// # use state_validation::{InputCondition, Project, RequiredIf, StateFilter, StateFilterRef};
+/// # #[derive(Debug)]
+/// # struct MissingError;
+/// # impl std::error::Error for MissingError {}
+/// # impl std::fmt::Display for MissingError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "missing")
+/// #     }
+/// # }
+/// struct Address {
+///     country: String,
+///     state: Option<String>,
+/// }
+/// struct InUS;
+/// impl<State> InputCondition<State, Address> for InUS {
+///     fn holds(_state: &State, address: &Address) -> bool {
+///         address.country == "US"
+///     }
+/// }
+/// struct AddressState;
+/// impl Project<Address> for AddressState {
+///     type Field = Option<String>;
+///     fn project(address: &Address) -> &Option<String> {
+///         &address.state
+///     }
+/// }
+/// struct Present;
+/// impl<State> StateFilterRef<State, Option<String>> for Present {
+///     type ValidOutput = ();
+///     type Error = MissingError;
+///     fn filter(_state: &State, value: &Option<String>) -> Result<(), Self::Error> {
+///         value.as_ref().map(|_| ()).ok_or(MissingError)
+///     }
+/// }
+///
+/// type StateRequiredInUS = RequiredIf<InUS, AddressState, Present>;
+/// let address = Address { country: "US".to_string(), state: None };
+/// assert!(StateRequiredInUS::filter(&(), address).is_err());
+/// let address = Address { country: "NL".to_string(), state: None };
+/// assert!(StateRequiredInUS::filter(&(), address).is_ok());
+/// ```
+pub struct RequiredIf<C, P, F>(std::marker::PhantomData<(C, P, F)>);
+
+impl<
+    State,
+    Input,
+    C: InputCondition<State, Input>,
+    P: Project<Input>,
+    F: StateFilterRef<State, P::Field>,
+> StateFilter<State, Input> for RequiredIf<C, P, F>
+{
+    type ValidOutput = Input;
+    type Error = F::Error;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        if C::holds(state, &value) {
+            F::filter(state, P::project(&value))?;
+        }
+        Ok(value)
+    }
+}
+
+/// Reads a value out of the state for [`WithStateData`].
+pub trait StateData<State> {
+    type Data;
+    fn get(state: &State) -> Self::Data;
+}
+
+/// Runs `F` on the input paired with the data `Getter` reads from the state.
+///
+/// Useful for consistency checks between the input and the state,
+/// such as a provided balance matching the stored balance.
+/// ```
+/// # use state_validation::{StateData, StateFilter, WithStateData};
+/// # #[derive(Debug)]
+/// # struct BalanceMismatchError;
+/// # impl std::error::Error for BalanceMismatchError {}
+/// # impl std::fmt::Display for BalanceMismatchError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "balance does not match")
+/// #     }
+/// # }
+/// struct Account {
+///     balance: u64,
+/// }
+/// struct Balance;
+/// impl StateData<Account> for Balance {
+///     type Data = u64;
+///     fn get(account: &Account) -> u64 {
+///         account.balance
+///     }
+/// }
+/// struct BalanceMatches;
+/// impl<State> StateFilter<State, (u64, u64)> for BalanceMatches {
+///     type ValidOutput = u64;
+///     type Error = BalanceMismatchError;
+///     fn filter(_state: &State, (provided, stored): (u64, u64)) -> Result<u64, Self::Error> {
+///         (provided == stored).then_some(provided).ok_or(BalanceMismatchError)
+///     }
+/// }
+///
+/// let account = Account { balance: 100 };
+/// type CheckBalance = WithStateData<Balance, BalanceMatches>;
+/// assert_eq!(CheckBalance::filter(&account, 100).unwrap(), 100);
+/// assert!(CheckBalance::filter(&account, 50).is_err());
+/// ```
+pub struct WithStateData<Getter, F>(std::marker::PhantomData<(Getter, F)>);
+
+impl<State, Input, Getter: StateData<State>, F: StateFilter<State, (Input, Getter::Data)>>
+    StateFilter<State, Input> for WithStateData<Getter, F>
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = F::Error;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, (value, Getter::get(state)))
+    }
+}
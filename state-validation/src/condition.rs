@@ -1 +1,77 @@
+/// A link in a chain of filters, running `Filter` on the `Input` taken out of the previous output.
+///
+/// Chains are tuples, and every element of a tuple must be a `Condition`,
+/// so the input to split off for each filter is known.
+/// A single filter does not need to be wrapped,
+/// since `Condition<Input, F>` on an `Input` behaves exactly like `F`,
+/// so the canonical form is a bare `F` on its own and `Condition`s in a tuple.
+/// ```
+/// # use state_validation::{Condition, StateFilter};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = std::num::NonZeroU32;
+///     type Error = ZeroError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         std::num::NonZeroU32::new(value).ok_or(ZeroError)
+///     }
+/// }
+///
+/// assert_eq!(
+///     NonZero::filter(&(), 5).unwrap(),
+///     Condition::<u32, NonZero>::filter(&(), 5).unwrap(),
+/// );
+/// ```
+/// Leaving a filter in a tuple unwrapped is rejected, pointing at `Condition`:
+/// ```compile_fail
+/// # use state_validation::{Condition, StateFilter, ValidAction};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// # struct NonZero;
+/// # impl<State> StateFilter<State, u32> for NonZero {
+/// #     type ValidOutput = u32;
+/// #     type Error = ZeroError;
+/// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+/// #     }
+/// # }
+/// struct Double;
+/// impl ValidAction<(), u32> for Double {
+///     // error: `NonZero` is not a `Condition`, so it cannot be an element of a chain
+///     type Filter = (NonZero, Condition<u32, NonZero>);
+///     type Output = u32;
+///     fn with_valid_input(self, _state: (), value: u32) -> Self::Output {
+///         value * 2
+///     }
+/// }
+/// ```
 pub struct Condition<Input, Filter>(std::marker::PhantomData<(Input, Filter)>);
+
+/// An element of a chain of filters, which is only ever a [`Condition`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `Condition`, so it cannot be an element of a chain",
+    note = "to chain filters, every element of the tuple must be a `Condition<Input, Filter>`",
+    note = "a single filter is used on its own, without a tuple or `Condition`"
+)]
+pub trait ChainLink {
+    type Input;
+    type Filter;
+}
+
+impl<Input, Filter> ChainLink for Condition<Input, Filter> {
+    type Input = Input;
+    type Filter = Filter;
+}
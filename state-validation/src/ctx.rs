@@ -0,0 +1,94 @@
+use crate::{StateFilter, ValidationError};
+
+/// A [`StateFilter`] which also reads from a context,
+/// such as feature flags or thresholds, that is not part of the state being acted upon.
+///
+/// Every [`StateFilter`] is a `StateFilterCtx` ignoring the context,
+/// so filters which do not need it can be used as is.
+pub trait StateFilterCtx<State, Ctx, Input> {
+    type ValidOutput;
+    type Error: std::error::Error;
+    fn filter(state: &State, ctx: &Ctx, value: Input) -> Result<Self::ValidOutput, Self::Error>;
+}
+
+impl<State, Ctx, Input, F: StateFilter<State, Input>> StateFilterCtx<State, Ctx, Input> for F {
+    type ValidOutput = F::ValidOutput;
+    type Error = F::Error;
+    fn filter(state: &State, _ctx: &Ctx, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, value)
+    }
+}
+
+/// A [`Validator`](crate::Validator) whose filter is a [`StateFilterCtx`],
+/// holding a reference to the context alongside the owned state.
+/// ```
+/// # use state_validation::{StateFilterCtx, ValidatorCtx};
+/// # #[derive(Debug)]
+/// # struct OverLimitError;
+/// # impl std::error::Error for OverLimitError {}
+/// # impl std::fmt::Display for OverLimitError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "over limit")
+/// #     }
+/// # }
+/// struct Config {
+///     max_deposit: u32,
+/// }
+/// struct Balance(u32);
+/// struct UnderLimit;
+/// impl StateFilterCtx<Balance, Config, u32> for UnderLimit {
+///     type ValidOutput = u32;
+///     type Error = OverLimitError;
+///     fn filter(_balance: &Balance, config: &Config, amount: u32) -> Result<u32, Self::Error> {
+///         if amount <= config.max_deposit { Ok(amount) } else { Err(OverLimitError) }
+///     }
+/// }
+///
+/// let config = Config { max_deposit: 100 };
+/// let validator = ValidatorCtx::<_, _, _, UnderLimit>::try_new(Balance(10), &config, 50).unwrap();
+/// let balance = validator.execute(|balance, _config, amount| Balance(balance.0 + amount));
+/// assert_eq!(balance.0, 60);
+/// assert!(ValidatorCtx::<_, _, _, UnderLimit>::try_new(balance, &config, 500).is_err());
+/// ```
+pub struct ValidatorCtx<'c, State, Ctx, Input, Filter: StateFilterCtx<State, Ctx, Input>> {
+    state: State,
+    ctx: &'c Ctx,
+    value: Filter::ValidOutput,
+    _p: std::marker::PhantomData<fn() -> Input>,
+}
+
+impl<'c, State, Ctx, Input, Filter: StateFilterCtx<State, Ctx, Input>>
+    ValidatorCtx<'c, State, Ctx, Input, Filter>
+{
+    pub fn try_new(
+        state: State,
+        ctx: &'c Ctx,
+        input: Input,
+    ) -> Result<Self, ValidationError<State, Filter::Error>> {
+        match Filter::filter(&state, ctx, input) {
+            Ok(value) => Ok(ValidatorCtx {
+                state,
+                ctx,
+                value,
+                _p: std::marker::PhantomData,
+            }),
+            Err(error) => Err(ValidationError { state, error }),
+        }
+    }
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+    pub fn ctx(&self) -> &'c Ctx {
+        self.ctx
+    }
+    pub fn valid_output(&self) -> &Filter::ValidOutput {
+        &self.value
+    }
+    /// Executes an action with the state, context and valid output.
+    pub fn execute<Output>(
+        self,
+        action: impl FnOnce(State, &'c Ctx, Filter::ValidOutput) -> Output,
+    ) -> Output {
+        action(self.state, self.ctx, self.value)
+    }
+}
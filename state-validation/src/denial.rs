@@ -0,0 +1,152 @@
+#[cfg(feature = "policy")]
+use crate::PolicyError;
+#[cfg(feature = "crypto")]
+use crate::filters::SignatureError;
+use crate::{
+    MissingCapabilityError, StateFilterEightChainError, StateFilterFiveChainError,
+    StateFilterFourChainError, StateFilterSevenChainError, StateFilterSixChainError,
+    StateFilterThreeChainError, StateFilterTwoChainError, VersionConflictError,
+    filters::{AbuseDetectedError, RateLimitedError, ReplayDetectedError},
+};
+
+/// A structured reason for denying an input, for recording denials in a security audit log.
+///
+/// Both methods have defaults, so an error without a specific reason
+/// only needs an empty `impl`, recording the code `"denied"` and its message.
+/// Chain errors implement it by forwarding to the error of the filter that failed,
+/// and the errors of the built-in filters record their own code and fields.
+/// ```
+/// # use state_validation::{DenialReason, StateFilterTwoChainError};
+/// #[derive(Debug)]
+/// struct SessionExpiredError;
+/// # impl std::error::Error for SessionExpiredError {}
+/// # impl std::fmt::Display for SessionExpiredError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "session expired")
+/// #     }
+/// # }
+/// impl DenialReason for SessionExpiredError {}
+/// #[derive(Debug)]
+/// struct MissingPermissionError {
+///     permission: &'static str,
+/// }
+/// # impl std::error::Error for MissingPermissionError {}
+/// # impl std::fmt::Display for MissingPermissionError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "missing permission {}", self.permission)
+/// #     }
+/// # }
+/// impl DenialReason for MissingPermissionError {
+///     fn reason_code(&self) -> &'static str {
+///         "missing_permission"
+///     }
+///     fn details(&self) -> serde_json::Value {
+///         serde_json::json!({ "permission": self.permission })
+///     }
+/// }
+///
+/// let error: StateFilterTwoChainError<SessionExpiredError, MissingPermissionError> =
+///     StateFilterTwoChainError::Filter1(MissingPermissionError { permission: "billing:write" });
+/// assert_eq!(error.reason_code(), "missing_permission");
+/// assert_eq!(error.details(), serde_json::json!({ "permission": "billing:write" }));
+/// let error: StateFilterTwoChainError<SessionExpiredError, MissingPermissionError> =
+///     StateFilterTwoChainError::Filter0(SessionExpiredError);
+/// assert_eq!(error.reason_code(), "denied");
+/// assert_eq!(error.details(), serde_json::json!({ "message": "session expired" }));
+/// ```
+pub trait DenialReason: std::error::Error {
+    fn reason_code(&self) -> &'static str {
+        "denied"
+    }
+    fn details(&self) -> serde_json::Value {
+        serde_json::json!({ "message": self.to_string() })
+    }
+}
+
+impl DenialReason for std::convert::Infallible {}
+
+macro_rules! impl_chain_denial_reason {
+    ($name:ident, $(($E:ident, $variant:ident, $index:tt)),*) => {
+        impl<$($E: DenialReason),*> DenialReason for $name<$($E),*> {
+            fn reason_code(&self) -> &'static str {
+                match self {
+                    $($name::$variant(e) => e.reason_code(),)*
+                }
+            }
+            fn details(&self) -> serde_json::Value {
+                match self {
+                    $($name::$variant(e) => e.details(),)*
+                }
+            }
+        }
+    };
+}
+for_each_chain_error!(impl_chain_denial_reason);
+
+impl DenialReason for MissingCapabilityError {
+    fn reason_code(&self) -> &'static str {
+        "missing_capability"
+    }
+    fn details(&self) -> serde_json::Value {
+        serde_json::json!({ "capability": self.capability })
+    }
+}
+
+#[cfg(feature = "policy")]
+impl DenialReason for PolicyError {
+    fn reason_code(&self) -> &'static str {
+        match self {
+            PolicyError::Denied { .. } => "policy_denied",
+            PolicyError::Query(_) => "policy_query_failed",
+        }
+    }
+    fn details(&self) -> serde_json::Value {
+        match self {
+            PolicyError::Denied { reason } => serde_json::json!({ "reason": reason }),
+            PolicyError::Query(error) => serde_json::json!({ "message": error.to_string() }),
+        }
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl DenialReason for SignatureError {
+    fn reason_code(&self) -> &'static str {
+        match self {
+            SignatureError::UnknownKey => "unknown_key",
+            SignatureError::BadSignature => "bad_signature",
+        }
+    }
+}
+
+impl DenialReason for RateLimitedError {
+    fn reason_code(&self) -> &'static str {
+        "rate_limited"
+    }
+    fn details(&self) -> serde_json::Value {
+        serde_json::json!({ "retry_after_secs": self.retry_after.as_secs_f64() })
+    }
+}
+
+impl<V: std::fmt::Debug + serde::Serialize> DenialReason for VersionConflictError<V> {
+    fn reason_code(&self) -> &'static str {
+        "version_conflict"
+    }
+    fn details(&self) -> serde_json::Value {
+        serde_json::json!({ "expected": self.expected, "current": self.current })
+    }
+}
+
+impl DenialReason for ReplayDetectedError {
+    fn reason_code(&self) -> &'static str {
+        "replay_detected"
+    }
+}
+
+impl DenialReason for AbuseDetectedError {
+    fn reason_code(&self) -> &'static str {
+        "abuse_detected"
+    }
+    fn details(&self) -> serde_json::Value {
+        serde_json::json!({ "count": self.count, "window_secs": self.window.as_secs_f64() })
+    }
+}
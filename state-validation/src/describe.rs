@@ -0,0 +1,47 @@
+use crate::Condition;
+
+/// Runtime introspection of the filters in a chain.
+///
+/// Implemented for [`Condition`], tuples of [`Condition`]s, and `()`.
+/// ```
+/// # use state_validation::{Condition, DescribableChain};
+/// struct UserExists;
+/// struct UserIsAdmin;
+/// type Chain = (Condition<u32, UserExists>, Condition<u64, UserIsAdmin>);
+///
+/// assert_eq!(Chain::LEN, 2);
+/// let names = Chain::describe();
+/// assert!(names[0].ends_with("UserExists"));
+/// assert!(names[1].ends_with("UserIsAdmin"));
+/// ```
+pub trait DescribableChain {
+    /// Amount of filters in the chain.
+    const LEN: usize;
+    /// Name of each filter in the order they are run.
+    fn describe() -> Vec<&'static str>;
+}
+
+impl DescribableChain for () {
+    const LEN: usize = 0;
+    fn describe() -> Vec<&'static str> {
+        Vec::new()
+    }
+}
+impl<Input, F> DescribableChain for Condition<Input, F> {
+    const LEN: usize = 1;
+    fn describe() -> Vec<&'static str> {
+        vec![std::any::type_name::<F>()]
+    }
+}
+
+macro_rules! impl_describable_chain {
+    ($(($Input:ident, $F:ident)),*) => {
+        impl<$($Input, $F),*> DescribableChain for ($(Condition<$Input, $F>,)*) {
+            const LEN: usize = [$(stringify!($F)),*].len();
+            fn describe() -> Vec<&'static str> {
+                vec![$(std::any::type_name::<$F>()),*]
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_describable_chain, 2, 8, Input, F);
@@ -48,13 +48,103 @@ impl<State, Input, Output> DynValidAction<State, Input, Output> {
     }
 }
 
-#[derive(Debug, thiserror::Error)]
+/// The state is given back for recovery, but is not needed to display the error.
+#[derive(thiserror::Error)]
+#[error("the action's filter failed")]
 pub struct DynValidActionExecutionError<State> {
     pub state: State,
     #[source]
     pub error: Box<dyn std::error::Error>,
 }
 
+impl<State> std::fmt::Debug for DynValidActionExecutionError<State> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+impl<State> DynValidActionExecutionError<State> {
+    /// The error of the filter, if it is an `E`.
+    /// ```
+    /// # use state_validation::{StateFilter, ValidAction, dynamic::DynValidAction};
+    /// #[derive(Debug)]
+    /// struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// #[derive(Clone)]
+    /// struct Deposit;
+    /// impl ValidAction<u32, u32> for Deposit {
+    ///     type Filter = NonZero;
+    ///     type Output = u32;
+    ///     fn with_valid_input(self, balance: u32, amount: u32) -> Self::Output {
+    ///         balance + amount
+    ///     }
+    /// }
+    ///
+    /// let error = DynValidAction::new(Deposit).execute_with_filter(10, 0).unwrap_err();
+    /// assert!(error.downcast_error::<ZeroError>().is_some());
+    /// assert!(error.downcast_error::<std::fmt::Error>().is_none());
+    /// ```
+    pub fn downcast_error<E: std::error::Error + 'static>(&self) -> Option<&E> {
+        self.error.downcast_ref()
+    }
+    /// Drops the state, leaving the error of the filter,
+    /// such as to collect it alongside other errors for logging.
+    /// ```
+    /// # use state_validation::{StateFilter, ValidAction, dynamic::DynValidAction};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// // Neither `Debug` nor `Display`.
+    /// struct Balance(u32);
+    /// #[derive(Clone)]
+    /// struct Deposit;
+    /// impl ValidAction<Balance, u32> for Deposit {
+    ///     type Filter = NonZero;
+    ///     type Output = Balance;
+    ///     fn with_valid_input(self, balance: Balance, amount: u32) -> Self::Output {
+    ///         Balance(balance.0 + amount)
+    ///     }
+    /// }
+    ///
+    /// let Err(error) = DynValidAction::new(Deposit).execute_with_filter(Balance(10), 0) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(error.to_string(), "the action's filter failed");
+    /// assert_eq!(std::error::Error::source(&error).unwrap().to_string(), "zero");
+    /// let errors: Vec<Box<dyn std::error::Error>> = vec![error.strip_state()];
+    /// assert!(errors[0].is::<ZeroError>());
+    /// ```
+    pub fn strip_state(self) -> Box<dyn std::error::Error> {
+        self.error
+    }
+}
+
 impl<State, Input, Output> ValidAction<State, Input> for DynValidAction<State, Input, Output> {
     type Filter = ();
     type Output = Result<Output, DynValidActionExecutionError<State>>;
@@ -63,6 +153,104 @@ impl<State, Input, Output> ValidAction<State, Input> for DynValidAction<State, I
     }
 }
 
+/// A [`DynValidAction`] which can only be executed once,
+/// so the action does not need to implement `Clone`.
+/// ```
+/// # use state_validation::{StateFilter, ValidAction, dynamic::DynValidActionOnce};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// # struct NonZero;
+/// # impl<State> StateFilter<State, u32> for NonZero {
+/// #     type ValidOutput = u32;
+/// #     type Error = ZeroError;
+/// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+/// #     }
+/// # }
+/// struct Deposit(String);
+/// impl ValidAction<u32, u32> for Deposit {
+///     type Filter = NonZero;
+///     type Output = (u32, String);
+///     fn with_valid_input(self, balance: u32, amount: u32) -> Self::Output {
+///         (balance + amount, self.0)
+///     }
+/// }
+///
+/// let action = DynValidActionOnce::new(Deposit("salary".to_string()));
+/// let (balance, memo) = action.execute_with_filter(10, 5).unwrap();
+/// assert_eq!(balance, 15);
+/// assert_eq!(memo, "salary");
+/// ```
+pub struct DynValidActionOnce<State, Input, Output> {
+    filter: DynStateFilter<State, Input, Box<dyn Any>>,
+    valid_action: Box<dyn Any>,
+    action: for<'a> fn(Box<dyn Any>, State, Box<dyn Any>) -> Output,
+}
+impl<State, Input, Output> std::fmt::Debug for DynValidActionOnce<State, Input, Output> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynValidActionOnce").finish_non_exhaustive()
+    }
+}
+
+impl<State, Input, Output> DynValidActionOnce<State, Input, Output> {
+    pub fn new<T: ValidAction<State, Input, Output = Output> + 'static>(valid_action: T) -> Self
+    where
+        <T::Filter as StateFilter<State, Input>>::ValidOutput: 'static,
+        <T::Filter as StateFilter<State, Input>>::Error: 'static,
+    {
+        DynValidActionOnce {
+            filter: DynStateFilter::new_with_any_output::<T::Filter>(),
+            valid_action: Box::new(valid_action),
+            action: |valid_action, state, valid| {
+                T::with_valid_input(
+                    *valid_action.downcast().unwrap(),
+                    state,
+                    *valid.downcast().unwrap(),
+                )
+            },
+        }
+    }
+    pub fn filter(&self) -> &DynStateFilter<State, Input, Box<dyn Any>> {
+        &self.filter
+    }
+    pub fn execute_with_filter(
+        self,
+        state: State,
+        input: Input,
+    ) -> Result<Output, DynValidActionExecutionError<State>> {
+        match self.filter.filter(&state, input) {
+            Ok(v) => Ok((self.action)(self.valid_action, state, v)),
+            Err(error) => Err(DynValidActionExecutionError { state, error }),
+        }
+    }
+}
+
+impl<State, Input, Output> ValidAction<State, Input> for DynValidActionOnce<State, Input, Output> {
+    type Filter = ();
+    type Output = Result<Output, DynValidActionExecutionError<State>>;
+    fn with_valid_input(self, state: State, input: Input) -> Self::Output {
+        self.execute_with_filter(state, input)
+    }
+}
+
+impl<State, Input, Output> From<DynValidAction<State, Input, Output>>
+    for DynValidActionOnce<State, Input, Output>
+{
+    fn from(value: DynValidAction<State, Input, Output>) -> Self {
+        DynValidActionOnce {
+            filter: value.filter,
+            valid_action: value.valid_action,
+            action: value.action,
+        }
+    }
+}
+
 impl<State, Input, Output> Clone for DynValidAction<State, Input, Output> {
     fn clone(&self) -> Self {
         DynValidAction {
@@ -0,0 +1,124 @@
+use crate::dynamic::DynValidActionOnce;
+
+/// Whether an action of a [`Batch`] passed its filter.
+pub type BatchResult = Result<(), Box<dyn std::error::Error>>;
+
+/// A list of actions, built at runtime, which are run one after the other on the state.
+///
+/// Every action takes the state the last action output,
+/// so a replayed command log can be applied in a single pass.
+/// ```
+/// # use state_validation::{StateFilter, ValidAction, dynamic::{Batch, DynValidAction}};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// # struct NonZero;
+/// # impl<State> StateFilter<State, u32> for NonZero {
+/// #     type ValidOutput = u32;
+/// #     type Error = ZeroError;
+/// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+/// #     }
+/// # }
+/// #[derive(Clone)]
+/// struct Deposit;
+/// impl ValidAction<u32, u32> for Deposit {
+///     type Filter = NonZero;
+///     type Output = u32;
+///     fn with_valid_input(self, balance: u32, amount: u32) -> Self::Output {
+///         balance + amount
+///     }
+/// }
+/// #[derive(Clone)]
+/// struct Double;
+/// impl ValidAction<u32, u32> for Double {
+///     type Filter = ();
+///     type Output = u32;
+///     fn with_valid_input(self, balance: u32, _: u32) -> Self::Output {
+///         balance * 2
+///     }
+/// }
+///
+/// let mut batch = Batch::new();
+/// batch.push(DynValidAction::new(Deposit), 5);
+/// batch.push(DynValidAction::new(Deposit), 0);
+/// batch.push(DynValidAction::new(Double), 0);
+/// let (balance, results) = batch.commit(10);
+/// assert_eq!(balance, 30);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// assert!(results[2].is_ok());
+/// ```
+pub struct Batch<State, Input> {
+    actions: Vec<(DynValidActionOnce<State, Input, State>, Input)>,
+}
+impl<State, Input> std::fmt::Debug for Batch<State, Input> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Batch")
+            .field(
+                "actions",
+                &self
+                    .actions
+                    .iter()
+                    .map(|(action, _)| action)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<State, Input> Default for Batch<State, Input> {
+    fn default() -> Self {
+        Batch {
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl<State, Input> Batch<State, Input> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds an action, along with the input it is run with.
+    pub fn push(
+        &mut self,
+        action: impl Into<DynValidActionOnce<State, Input, State>>,
+        input: Input,
+    ) {
+        self.actions.push((action.into(), input));
+    }
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+    /// Runs every action with its input, in the order they were pushed,
+    /// returning the final state and whether each action passed its filter.
+    ///
+    /// An action whose filter fails is skipped, leaving the state as it was.
+    pub fn commit(self, state: State) -> (State, Vec<BatchResult>) {
+        let mut results = Vec::with_capacity(self.actions.len());
+        let state = self
+            .actions
+            .into_iter()
+            .fold(state, |state, (action, input)| {
+                match action.execute_with_filter(state, input) {
+                    Ok(state) => {
+                        results.push(Ok(()));
+                        state
+                    }
+                    Err(error) => {
+                        results.push(Err(error.error));
+                        error.state
+                    }
+                }
+            });
+        (state, results)
+    }
+}
@@ -0,0 +1,110 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use crate::{StateFilter, dynamic::DynStateFilter};
+
+/// Routes a boxed input to the filter registered for its concrete type.
+/// ```
+/// # use std::any::Any;
+/// # use state_validation::{StateFilter, dynamic::{TypeDispatch, TypeDispatchError}};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = String;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value == 0 { Err(InvalidError) } else { Ok(value.to_string()) }
+///     }
+/// }
+/// struct NotBlank;
+/// impl<State> StateFilter<State, String> for NotBlank {
+///     type ValidOutput = String;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+///         if value.trim().is_empty() { Err(InvalidError) } else { Ok(value) }
+///     }
+/// }
+///
+/// let mut dispatch = TypeDispatch::<(), String>::new();
+/// dispatch.register::<u32, NonZero>().register::<String, NotBlank>();
+///
+/// assert_eq!(dispatch.filter(&(), Box::new(5u32)).unwrap(), "5");
+/// assert_eq!(dispatch.filter(&(), Box::new("admin".to_string())).unwrap(), "admin");
+/// assert!(matches!(
+///     dispatch.filter(&(), Box::new(0u32)),
+///     Err(TypeDispatchError::Filter(_)),
+/// ));
+/// assert!(matches!(
+///     dispatch.filter(&(), Box::new(1.5f32)),
+///     Err(TypeDispatchError::Unregistered(_)),
+/// ));
+/// ```
+pub struct TypeDispatch<State, ValidOutput> {
+    filters: HashMap<TypeId, DynStateFilter<State, Box<dyn Any>, ValidOutput>>,
+}
+
+impl<State, ValidOutput> TypeDispatch<State, ValidOutput> {
+    pub fn new() -> Self {
+        TypeDispatch {
+            filters: HashMap::new(),
+        }
+    }
+    /// Registers `F` for inputs of type `Input`, replacing any filter registered before it.
+    pub fn register<Input: 'static, F: StateFilter<State, Input>>(&mut self) -> &mut Self
+    where
+        F::ValidOutput: Into<ValidOutput>,
+        F::Error: 'static,
+    {
+        self.filters.insert(
+            TypeId::of::<Input>(),
+            DynStateFilter::new_downcast::<Input, F>(),
+        );
+        self
+    }
+    pub fn is_registered<Input: 'static>(&self) -> bool {
+        self.filters.contains_key(&TypeId::of::<Input>())
+    }
+    pub fn filter(
+        &self,
+        state: &State,
+        input: Box<dyn Any>,
+    ) -> Result<ValidOutput, TypeDispatchError> {
+        match self.filters.get(&(*input).type_id()) {
+            Some(filter) => filter
+                .filter(state, input)
+                .map_err(TypeDispatchError::Filter),
+            None => Err(TypeDispatchError::Unregistered(input)),
+        }
+    }
+}
+
+impl<State, ValidOutput> Default for TypeDispatch<State, ValidOutput> {
+    fn default() -> Self {
+        TypeDispatch::new()
+    }
+}
+
+#[derive(thiserror::Error)]
+pub enum TypeDispatchError {
+    /// No filter was registered for the input's type, so the input is given back.
+    #[error("no filter registered for this input type")]
+    Unregistered(Box<dyn Any>),
+    #[error(transparent)]
+    Filter(Box<dyn std::error::Error>),
+}
+
+impl std::fmt::Debug for TypeDispatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeDispatchError::Unregistered(_) => f.write_str("Unregistered(..)"),
+            TypeDispatchError::Filter(error) => f.debug_tuple("Filter").field(error).finish(),
+        }
+    }
+}
@@ -1,4 +1,8 @@
 mod action;
+mod batch;
+mod dispatch;
 mod state_filter;
 pub use action::*;
+pub use batch::*;
+pub use dispatch::*;
 pub use state_filter::*;
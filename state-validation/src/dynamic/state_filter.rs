@@ -1,8 +1,9 @@
 use crate::StateFilter;
 
-pub struct DynStateFilter<State, Input, ValidOutput>(
-    for<'a> fn(&State, Input) -> Result<ValidOutput, Box<dyn std::error::Error>>,
-);
+type DynFilterFn<State, Input, ValidOutput> =
+    for<'a> fn(&State, Input) -> Result<ValidOutput, Box<dyn std::error::Error>>;
+
+pub struct DynStateFilter<State, Input, ValidOutput>(DynFilterFn<State, Input, ValidOutput>);
 
 impl<State, Input, ValidOutput> DynStateFilter<State, Input, ValidOutput> {
     pub fn new<T: StateFilter<State, Input>>() -> Self
@@ -36,6 +37,22 @@ impl<State, Input> DynStateFilter<State, Input, Box<dyn std::any::Any>> {
     }
 }
 
+impl<State, ValidOutput> DynStateFilter<State, Box<dyn std::any::Any>, ValidOutput> {
+    /// Panics if the boxed input is not `Input`.
+    pub(crate) fn new_downcast<Input: 'static, T: StateFilter<State, Input>>() -> Self
+    where
+        T::ValidOutput: Into<ValidOutput>,
+        T::Error: 'static,
+    {
+        DynStateFilter(
+            |state, input| match T::filter(state, *input.downcast().unwrap()) {
+                Ok(v) => Ok(v.into()),
+                Err(e) => Err(Box::new(e)),
+            },
+        )
+    }
+}
+
 impl<State, Input, ValidOutput> Clone for DynStateFilter<State, Input, ValidOutput> {
     fn clone(&self) -> Self {
         DynStateFilter(self.0)
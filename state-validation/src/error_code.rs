@@ -0,0 +1,65 @@
+use crate::{
+    StateFilterEightChainError, StateFilterFiveChainError, StateFilterFourChainError,
+    StateFilterSevenChainError, StateFilterSixChainError, StateFilterThreeChainError,
+    StateFilterTwoChainError,
+};
+
+/// A cheap code identifying an error, for when only routing or metrics need it
+/// and formatting the error with `Display` would be wasted work.
+///
+/// Chain errors implement it by forwarding to the error of the filter that failed.
+/// ```
+/// # use state_validation::{ErrorCode, StateFilterTwoChainError};
+/// #[derive(Debug)]
+/// struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// impl ErrorCode for UserDoesNotExistError {
+///     fn code(&self) -> u32 {
+///         404
+///     }
+/// }
+/// #[derive(Debug)]
+/// struct UserIsNotAdminError;
+/// # impl std::error::Error for UserIsNotAdminError {}
+/// # impl std::fmt::Display for UserIsNotAdminError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user is not an admin")
+/// #     }
+/// # }
+/// impl ErrorCode for UserIsNotAdminError {
+///     fn code(&self) -> u32 {
+///         403
+///     }
+/// }
+///
+/// let error: StateFilterTwoChainError<UserDoesNotExistError, UserIsNotAdminError> =
+///     StateFilterTwoChainError::Filter1(UserIsNotAdminError);
+/// assert_eq!(error.code(), 403);
+/// ```
+pub trait ErrorCode {
+    fn code(&self) -> u32;
+}
+
+impl ErrorCode for std::convert::Infallible {
+    fn code(&self) -> u32 {
+        match *self {}
+    }
+}
+
+macro_rules! impl_chain_error_code {
+    ($name:ident, $(($E:ident, $variant:ident, $index:tt)),*) => {
+        impl<$($E: std::error::Error + ErrorCode),*> ErrorCode for $name<$($E),*> {
+            fn code(&self) -> u32 {
+                match self {
+                    $($name::$variant(e) => e.code(),)*
+                }
+            }
+        }
+    };
+}
+for_each_chain_error!(impl_chain_error_code);
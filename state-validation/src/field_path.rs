@@ -0,0 +1,143 @@
+use crate::{
+    Condition, StateFilterEightChainError, StateFilterFiveChainError, StateFilterFourChainError,
+    StateFilterSevenChainError, StateFilterSixChainError, StateFilterThreeChainError,
+    StateFilterTwoChainError,
+};
+
+/// Name of the field of `Self` which is split out as `T`.
+///
+/// Generated by [`StateFilterConversion`](crate::StateFilterConversion)
+/// with `#[conversion(field_paths)]`, for every type only a single field can be split into.
+pub trait FieldPath<T> {
+    const FIELD_PATH: &'static str;
+}
+
+/// Maps the index of a filter in a chain to the field of `Original` it validates.
+///
+/// Together with [`FilterIndex`], a chain error can report the field that failed.
+/// ```
+/// # use state_validation::{Condition, FieldPaths, FilterIndex, StateFilter, StateFilterConversion};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// # struct Username(String);
+/// # struct Email(String);
+/// # struct NotEmpty;
+/// # impl<State> StateFilter<State, Username> for NotEmpty {
+/// #     type ValidOutput = Username;
+/// #     type Error = InvalidError;
+/// #     fn filter(_: &State, value: Username) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value.0.is_empty() { Err(InvalidError) } else { Ok(value) }
+/// #     }
+/// # }
+/// # struct HasAt;
+/// # impl<State> StateFilter<State, Email> for HasAt {
+/// #     type ValidOutput = Email;
+/// #     type Error = InvalidError;
+/// #     fn filter(_: &State, value: Email) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value.0.contains('@') { Ok(value) } else { Err(InvalidError) }
+/// #     }
+/// # }
+/// #[derive(StateFilterConversion)]
+/// #[conversion(field_paths)]
+/// struct Registration {
+///     username: Username,
+///     email: Email,
+/// }
+/// type Chain = (Condition<Username, NotEmpty>, Condition<Email, HasAt>);
+///
+/// let registration = Registration {
+///     username: Username("admin".to_string()),
+///     email: Email("admin".to_string()),
+/// };
+/// let error = Chain::filter(&(), registration).err().unwrap();
+/// assert_eq!(
+///     <Chain as FieldPaths<Registration>>::field_path(error.filter_index()),
+///     Some("email"),
+/// );
+/// ```
+pub trait FieldPaths<Original> {
+    fn field_path(index: usize) -> Option<&'static str>;
+}
+
+impl<Original: FieldPath<Input>, Input, F> FieldPaths<Original> for Condition<Input, F> {
+    fn field_path(index: usize) -> Option<&'static str> {
+        (index == 0).then_some(Original::FIELD_PATH)
+    }
+}
+
+macro_rules! impl_field_paths {
+    ($(($Input:ident, $F:ident)),*) => {
+        impl<Original: $(FieldPath<$Input> +)*, $($Input, $F),*> FieldPaths<Original>
+            for ($(Condition<$Input, $F>,)*)
+        {
+            fn field_path(index: usize) -> Option<&'static str> {
+                [$(<Original as FieldPath<$Input>>::FIELD_PATH),*].get(index).copied()
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_field_paths, 2, 8, Input, F);
+
+/// Index of the filter in the chain that failed.
+pub trait FilterIndex {
+    fn filter_index(&self) -> usize;
+}
+
+macro_rules! impl_chain_error_filter_index {
+    ($name:ident, $(($E:ident, $variant:ident, $index:tt)),*) => {
+        impl<$($E: std::error::Error),*> FilterIndex for $name<$($E),*> {
+            fn filter_index(&self) -> usize {
+                match self {
+                    $($name::$variant(_) => $index,)*
+                }
+            }
+        }
+    };
+}
+for_each_chain_error!(impl_chain_error_filter_index);
+impl FilterIndex for std::convert::Infallible {
+    fn filter_index(&self) -> usize {
+        match *self {}
+    }
+}
+
+/// Reduces an error to the index of the filter that failed and its message,
+/// so error-reporting code can handle chains of any length the same way.
+///
+/// Only one filter in a chain can fail, so there is only ever one entry.
+/// The error of a single filter, which is not in a chain, is flattened with [`flatten_filter_error`].
+/// ```
+/// # use state_validation::{FlattenChainError, StateFilterThreeChainError, flatten_filter_error};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// let error: StateFilterThreeChainError<InvalidError, InvalidError, InvalidError> =
+///     StateFilterThreeChainError::Filter2(InvalidError);
+/// assert_eq!(error.flatten(), vec![(2, "invalid".to_string())]);
+/// assert_eq!(flatten_filter_error(&InvalidError), vec![(0, "invalid".to_string())]);
+/// ```
+pub trait FlattenChainError {
+    fn flatten(&self) -> Vec<(usize, String)>;
+}
+
+impl<E: FilterIndex + std::fmt::Display> FlattenChainError for E {
+    fn flatten(&self) -> Vec<(usize, String)> {
+        vec![(self.filter_index(), self.to_string())]
+    }
+}
+
+/// Flattens the error of a single filter, which is always at index `0`.
+pub fn flatten_filter_error<E: std::error::Error>(error: &E) -> Vec<(usize, String)> {
+    vec![(0, error.to_string())]
+}
@@ -0,0 +1,82 @@
+use crate::StateFilter;
+
+/// How deeply a value is nested, with values holding no nested values at a depth of `0`.
+pub trait Depth {
+    fn depth(&self) -> usize;
+}
+
+impl<T: Depth> Depth for Vec<T> {
+    fn depth(&self) -> usize {
+        1 + self.iter().map(Depth::depth).max().unwrap_or(0)
+    }
+}
+impl<T: Depth> Depth for Option<T> {
+    fn depth(&self) -> usize {
+        self.as_ref().map_or(0, Depth::depth)
+    }
+}
+impl<T: Depth + ?Sized> Depth for Box<T> {
+    fn depth(&self) -> usize {
+        (**self).depth()
+    }
+}
+#[cfg(feature = "serde")]
+impl Depth for serde_json::Value {
+    fn depth(&self) -> usize {
+        match self {
+            serde_json::Value::Array(values) => {
+                1 + values.iter().map(Depth::depth).max().unwrap_or(0)
+            }
+            serde_json::Value::Object(values) => {
+                1 + values.values().map(Depth::depth).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// Passes if the input is nested at most `D` levels deep,
+/// guarding the filters after it, which may recurse, from maliciously nested inputs.
+/// ```
+/// # use state_validation::{StateFilter, filters::{Depth, MaxDepth}};
+/// #[derive(Debug)]
+/// enum Tree {
+///     Leaf,
+///     Node(Vec<Tree>),
+/// }
+/// impl Depth for Tree {
+///     fn depth(&self) -> usize {
+///         match self {
+///             Tree::Leaf => 0,
+///             Tree::Node(children) => children.depth(),
+///         }
+///     }
+/// }
+///
+/// let shallow = Tree::Node(vec![Tree::Leaf, Tree::Node(vec![Tree::Leaf])]);
+/// assert!(MaxDepth::<4>::filter(&(), shallow).is_ok());
+/// let deep = (0..100).fold(Tree::Leaf, |tree, _| Tree::Node(vec![tree]));
+/// let error = MaxDepth::<4>::filter(&(), deep).unwrap_err();
+/// assert_eq!((error.max, error.got), (4, 100));
+/// ```
+pub struct MaxDepth<const D: usize>;
+
+impl<State, Input: Depth, const D: usize> StateFilter<State, Input> for MaxDepth<D> {
+    type ValidOutput = Input;
+    type Error = TooDeepError;
+    fn filter(_state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let depth = value.depth();
+        if depth > D {
+            Err(TooDeepError { max: D, got: depth })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("input is nested {got} levels deep, more than the limit of {max}")]
+pub struct TooDeepError {
+    pub max: usize,
+    pub got: usize,
+}
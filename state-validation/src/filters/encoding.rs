@@ -0,0 +1,53 @@
+use base64::Engine;
+
+use crate::StateFilter;
+
+/// Decodes standard, padded base64 into bytes.
+/// ```
+/// # use state_validation::{StateFilter, filters::Base64Decode};
+/// assert_eq!(Base64Decode::filter(&(), "aGk=").unwrap(), b"hi");
+/// let error = Base64Decode::filter(&(), "not base64!".to_string()).unwrap_err();
+/// assert!(error.to_string().contains("Invalid"));
+/// ```
+pub struct Base64Decode;
+
+impl<State> StateFilter<State, String> for Base64Decode {
+    type ValidOutput = Vec<u8>;
+    type Error = base64::DecodeError;
+    fn filter(state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+        <Self as StateFilter<State, &str>>::filter(state, &value)
+    }
+}
+impl<State> StateFilter<State, &str> for Base64Decode {
+    type ValidOutput = Vec<u8>;
+    type Error = base64::DecodeError;
+    fn filter(_state: &State, value: &str) -> Result<Self::ValidOutput, Self::Error> {
+        base64::engine::general_purpose::STANDARD.decode(value)
+    }
+}
+
+/// Decodes hex, in either case, into bytes.
+/// ```
+/// # use state_validation::{StateFilter, filters::HexDecode};
+/// assert_eq!(HexDecode::filter(&(), "6869").unwrap(), b"hi");
+/// assert_eq!(HexDecode::filter(&(), "6A".to_string()).unwrap(), b"j");
+/// let error = HexDecode::filter(&(), "zz").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid character 'z' at position 0");
+/// assert!(HexDecode::filter(&(), "abc").is_err());
+/// ```
+pub struct HexDecode;
+
+impl<State> StateFilter<State, String> for HexDecode {
+    type ValidOutput = Vec<u8>;
+    type Error = hex::FromHexError;
+    fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+        hex::decode(value)
+    }
+}
+impl<State> StateFilter<State, &str> for HexDecode {
+    type ValidOutput = Vec<u8>;
+    type Error = hex::FromHexError;
+    fn filter(_state: &State, value: &str) -> Result<Self::ValidOutput, Self::Error> {
+        hex::decode(value)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{StateFilter, StateFilterInputCombination, StateFilterInputConversion};
+
+/// Passes if the fields of type `A` and `B` are equal,
+/// such as a password and its confirmation.
+///
+/// The fields are split out of the input with [`StateFilterInputConversion`]
+/// and combined back afterward, so the input may be a struct deriving
+/// [`StateFilterConversion`](crate::StateFilterConversion), or just a tuple.
+/// ```
+/// # use state_validation::{StateFilter, filters::{FieldsEqual, FieldsOrdered}};
+/// let passwords = ("hunter2".to_string(), "hunter2".to_string());
+/// assert!(FieldsEqual::<String, String>::filter(&(), passwords).is_ok());
+///
+/// let dates = (2, 1);
+/// let error = FieldsOrdered::<u32, u32>::filter(&(), dates).unwrap_err();
+/// assert_eq!((error.a, error.b), (2, 1));
+/// ```
+pub struct FieldsEqual<A, B>(std::marker::PhantomData<(A, B)>);
+/// Passes if the field of type `A` is less than the field of type `B`,
+/// such as a start date and an end date.
+pub struct FieldsOrdered<A, B>(std::marker::PhantomData<(A, B)>);
+
+impl<State, Input, A: PartialEq<B> + std::fmt::Debug, B: std::fmt::Debug> StateFilter<State, Input>
+    for FieldsEqual<A, B>
+where
+    Input: StateFilterInputConversion<(A, B)>,
+    Input::Remainder: StateFilterInputCombination<(A, B)>,
+{
+    type ValidOutput = <Input::Remainder as StateFilterInputCombination<(A, B)>>::Combined;
+    type Error = FieldsNotEqualError<A, B>;
+    fn filter(_state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let ((a, b), remainder) = value.split_take();
+        if a == b {
+            Ok(remainder.combine((a, b)))
+        } else {
+            Err(FieldsNotEqualError { a, b })
+        }
+    }
+}
+impl<State, Input, A: PartialOrd<B> + std::fmt::Debug, B: std::fmt::Debug> StateFilter<State, Input>
+    for FieldsOrdered<A, B>
+where
+    Input: StateFilterInputConversion<(A, B)>,
+    Input::Remainder: StateFilterInputCombination<(A, B)>,
+{
+    type ValidOutput = <Input::Remainder as StateFilterInputCombination<(A, B)>>::Combined;
+    type Error = FieldsNotOrderedError<A, B>;
+    fn filter(_state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let ((a, b), remainder) = value.split_take();
+        if a < b {
+            Ok(remainder.combine((a, b)))
+        } else {
+            Err(FieldsNotOrderedError { a, b })
+        }
+    }
+}
+
+/// The fields that were not equal, given back so they can be inspected or reused.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("`{}` is not equal to `{}`", std::any::type_name::<A>(), std::any::type_name::<B>())]
+pub struct FieldsNotEqualError<A: std::fmt::Debug, B: std::fmt::Debug> {
+    pub a: A,
+    pub b: B,
+}
+
+/// The fields that were out of order, given back so they can be inspected or reused.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("`{}` is not less than `{}`", std::any::type_name::<A>(), std::any::type_name::<B>())]
+pub struct FieldsNotOrderedError<A: std::fmt::Debug, B: std::fmt::Debug> {
+    pub a: A,
+    pub b: B,
+}
@@ -0,0 +1,131 @@
+use crate::StateFilter;
+
+/// Passes if the collection has at least one element.
+///
+/// A `Vec<T>` is output as a [`NonEmptyVec<T>`],
+/// so code further down the line does not need to check for emptiness again.
+/// ```
+/// # use state_validation::{StateFilter, filters::{NonEmpty, MaxLen}};
+/// let items = NonEmpty::filter(&(), vec![1, 2, 3]).unwrap();
+/// assert_eq!(*items.first(), 1);
+/// assert!(NonEmpty::filter(&(), Vec::<u8>::new()).is_err());
+///
+/// let error = MaxLen::<2>::filter(&(), vec![1, 2, 3]).unwrap_err();
+/// assert_eq!(error.len, 3);
+/// ```
+pub struct NonEmpty;
+/// Passes if the collection has at most `N` elements.
+pub struct MaxLen<const N: usize>;
+/// Passes if the collection has at least `N` elements.
+pub struct MinLen<const N: usize>;
+
+impl<State, T> StateFilter<State, Vec<T>> for NonEmpty {
+    type ValidOutput = NonEmptyVec<T>;
+    type Error = EmptyError;
+    fn filter(_state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(NonEmptyVec(value))
+        }
+    }
+}
+impl<'a, State, T> StateFilter<State, &'a [T]> for NonEmpty {
+    type ValidOutput = &'a [T];
+    type Error = EmptyError;
+    fn filter(_state: &State, value: &'a [T]) -> Result<Self::ValidOutput, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(value)
+        }
+    }
+}
+impl<State, T, const N: usize> StateFilter<State, Vec<T>> for MaxLen<N> {
+    type ValidOutput = Vec<T>;
+    type Error = TooLongError;
+    fn filter(_state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        check_max_len::<N>(value.len()).map(|()| value)
+    }
+}
+impl<'a, State, T, const N: usize> StateFilter<State, &'a [T]> for MaxLen<N> {
+    type ValidOutput = &'a [T];
+    type Error = TooLongError;
+    fn filter(_state: &State, value: &'a [T]) -> Result<Self::ValidOutput, Self::Error> {
+        check_max_len::<N>(value.len()).map(|()| value)
+    }
+}
+impl<State, T, const N: usize> StateFilter<State, Vec<T>> for MinLen<N> {
+    type ValidOutput = Vec<T>;
+    type Error = TooShortError;
+    fn filter(_state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        check_min_len::<N>(value.len()).map(|()| value)
+    }
+}
+impl<'a, State, T, const N: usize> StateFilter<State, &'a [T]> for MinLen<N> {
+    type ValidOutput = &'a [T];
+    type Error = TooShortError;
+    fn filter(_state: &State, value: &'a [T]) -> Result<Self::ValidOutput, Self::Error> {
+        check_min_len::<N>(value.len()).map(|()| value)
+    }
+}
+
+fn check_max_len<const N: usize>(len: usize) -> Result<(), TooLongError> {
+    if len > N {
+        Err(TooLongError { len, max: N })
+    } else {
+        Ok(())
+    }
+}
+fn check_min_len<const N: usize>(len: usize) -> Result<(), TooShortError> {
+    if len < N {
+        Err(TooShortError { len, min: N })
+    } else {
+        Ok(())
+    }
+}
+
+/// A `Vec<T>` which is guaranteed to have at least one element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+impl<T> std::ops::Deref for NonEmptyVec<T> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(value: NonEmptyVec<T>) -> Self {
+        value.0
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected at least one element, but was empty")]
+pub struct EmptyError;
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected at most {max} elements, but got {len}")]
+pub struct TooLongError {
+    pub len: usize,
+    pub max: usize,
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected at least {min} elements, but got {len}")]
+pub struct TooShortError {
+    pub len: usize,
+    pub min: usize,
+}
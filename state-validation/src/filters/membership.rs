@@ -0,0 +1,81 @@
+use std::collections::{BTreeSet, HashSet};
+
+use crate::StateFilter;
+
+/// A set which may report keys it does not contain, but never misses a key it does contain,
+/// such as a Bloom filter or cuckoo filter.
+pub trait Membership<K> {
+    /// `false` if the key is definitely absent, `true` if it might be present.
+    fn might_contain(&self, key: &K) -> bool;
+}
+
+impl<K: std::hash::Hash + Eq> Membership<K> for HashSet<K> {
+    fn might_contain(&self, key: &K) -> bool {
+        self.contains(key)
+    }
+}
+
+impl<K: Ord> Membership<K> for BTreeSet<K> {
+    fn might_contain(&self, key: &K) -> bool {
+        self.contains(key)
+    }
+}
+
+/// Projects a [`Membership`] structure out of the state, for [`ProbablyExists`] to check against.
+pub trait StateMembership<State, K> {
+    type Set: Membership<K>;
+    fn membership(state: &State) -> &Self::Set;
+}
+
+/// Passes if the key might be in the structure `Getter` projects out of the state.
+///
+/// A structure like a Bloom filter may have false positives, so passing does not prove the key exists,
+/// only failing proves it does not. Use this to cheaply reject keys before an exact check,
+/// or where an occasional false positive is acceptable.
+/// ```
+/// # use state_validation::{StateFilter, filters::{Membership, ProbablyExists, StateMembership}};
+/// // A one-hash Bloom filter.
+/// struct Bloom([bool; 8]);
+/// impl Membership<u32> for Bloom {
+///     fn might_contain(&self, key: &u32) -> bool {
+///         self.0[*key as usize % 8]
+///     }
+/// }
+/// struct Index {
+///     users: Bloom,
+/// }
+/// struct Users;
+/// impl StateMembership<Index, u32> for Users {
+///     type Set = Bloom;
+///     fn membership(index: &Index) -> &Bloom {
+///         &index.users
+///     }
+/// }
+///
+/// let mut users = Bloom([false; 8]);
+/// users.0[3] = true;
+/// let index = Index { users };
+/// assert_eq!(ProbablyExists::<Users>::filter(&index, 3), Ok(3));
+/// assert!(ProbablyExists::<Users>::filter(&index, 4).is_err());
+/// // A false positive.
+/// assert_eq!(ProbablyExists::<Users>::filter(&index, 11), Ok(11));
+/// ```
+pub struct ProbablyExists<Getter>(std::marker::PhantomData<Getter>);
+
+impl<State, K: std::fmt::Debug, Getter: StateMembership<State, K>> StateFilter<State, K>
+    for ProbablyExists<Getter>
+{
+    type ValidOutput = K;
+    type Error = DefinitelyAbsentError<K>;
+    fn filter(state: &State, value: K) -> Result<Self::ValidOutput, Self::Error> {
+        if Getter::membership(state).might_contain(&value) {
+            Ok(value)
+        } else {
+            Err(DefinitelyAbsentError(value))
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{0:?} definitely does not exist")]
+pub struct DefinitelyAbsentError<K: std::fmt::Debug>(pub K);
@@ -0,0 +1,38 @@
+mod depth;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod fields;
+mod length;
+mod membership;
+mod nonce;
+mod option;
+mod parse;
+mod rate_limit;
+mod reference;
+mod remote;
+mod sequence;
+mod set;
+#[cfg(feature = "crypto")]
+mod signature;
+mod sliding_window;
+mod text;
+mod unique;
+pub use depth::*;
+#[cfg(feature = "encoding")]
+pub use encoding::*;
+pub use fields::*;
+pub use length::*;
+pub use membership::*;
+pub use nonce::*;
+pub use option::*;
+pub use parse::*;
+pub use rate_limit::*;
+pub use reference::*;
+pub use remote::*;
+pub use sequence::*;
+pub use set::*;
+#[cfg(feature = "crypto")]
+pub use signature::*;
+pub use sliding_window::*;
+pub use text::*;
+pub use unique::*;
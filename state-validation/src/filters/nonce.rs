@@ -0,0 +1,131 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::StateFilterMut;
+
+/// The nonces seen so far, for [`FreshNonce`] to check against.
+pub trait NonceStore<N> {
+    fn contains(&self, nonce: &N) -> bool;
+    fn insert(&mut self, nonce: N);
+}
+
+impl<N: Hash + Eq> NonceStore<N> for HashSet<N> {
+    fn contains(&self, nonce: &N) -> bool {
+        HashSet::contains(self, nonce)
+    }
+    fn insert(&mut self, nonce: N) {
+        HashSet::insert(self, nonce);
+    }
+}
+
+/// Remembers only the last `capacity` nonces, forgetting the oldest,
+/// so the store does not grow without bound.
+///
+/// A forgotten nonce may be replayed, so pair it with a filter rejecting old timestamps.
+/// ```
+/// # use state_validation::filters::{BoundedNonces, NonceStore};
+/// let mut nonces = BoundedNonces::new(2);
+/// nonces.insert(1);
+/// nonces.insert(1);
+/// nonces.insert(2);
+/// assert!(nonces.contains(&1));
+/// nonces.insert(3);
+/// assert!(!nonces.contains(&1));
+/// assert!(nonces.contains(&2) && nonces.contains(&3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedNonces<N> {
+    capacity: usize,
+    seen: HashSet<N>,
+    order: VecDeque<N>,
+}
+
+impl<N> BoundedNonces<N> {
+    pub fn new(capacity: usize) -> Self {
+        BoundedNonces {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+}
+
+impl<N: Hash + Eq + Clone> NonceStore<N> for BoundedNonces<N> {
+    fn contains(&self, nonce: &N) -> bool {
+        self.seen.contains(nonce)
+    }
+    fn insert(&mut self, nonce: N) {
+        if self.capacity == 0 || !self.seen.insert(nonce.clone()) {
+            return;
+        }
+        if self.order.len() == self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        self.order.push_back(nonce);
+    }
+}
+
+/// Projects the nonces seen so far out of the state.
+pub trait StateNonces<State, N> {
+    type Store: NonceStore<N>;
+    fn nonces(state: &mut State) -> &mut Self::Store;
+}
+
+/// Passes if the nonce has not been seen before, recording it as seen,
+/// to protect against replayed requests.
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{StateFilterMut, filters::{BoundedNonces, FreshNonce, StateNonces}};
+/// struct Server {
+///     nonces: HashSet<u64>,
+/// }
+/// struct SeenNonces;
+/// impl StateNonces<Server, u64> for SeenNonces {
+///     type Store = HashSet<u64>;
+///     fn nonces(server: &mut Server) -> &mut HashSet<u64> {
+///         &mut server.nonces
+///     }
+/// }
+///
+/// let mut server = Server { nonces: HashSet::new() };
+/// assert_eq!(FreshNonce::<SeenNonces>::filter(&mut server, 7).unwrap(), 7);
+/// assert!(FreshNonce::<SeenNonces>::filter(&mut server, 7).is_err());
+///
+/// struct BoundedServer(BoundedNonces<u64>);
+/// struct LastNonces;
+/// impl StateNonces<BoundedServer, u64> for LastNonces {
+///     type Store = BoundedNonces<u64>;
+///     fn nonces(server: &mut BoundedServer) -> &mut BoundedNonces<u64> {
+///         &mut server.0
+///     }
+/// }
+/// let mut server = BoundedServer(BoundedNonces::new(1));
+/// assert!(FreshNonce::<LastNonces>::filter(&mut server, 1).is_ok());
+/// assert!(FreshNonce::<LastNonces>::filter(&mut server, 2).is_ok());
+/// // `1` was forgotten to make room for `2`.
+/// assert!(FreshNonce::<LastNonces>::filter(&mut server, 1).is_ok());
+/// assert!(FreshNonce::<LastNonces>::filter(&mut server, 1).is_err());
+/// ```
+pub struct FreshNonce<Getter>(std::marker::PhantomData<Getter>);
+
+impl<State, N: Clone, Getter: StateNonces<State, N>> StateFilterMut<State, N>
+    for FreshNonce<Getter>
+{
+    type ValidOutput = N;
+    type Error = ReplayDetectedError;
+    fn filter(state: &mut State, value: N) -> Result<Self::ValidOutput, Self::Error> {
+        let nonces = Getter::nonces(state);
+        if nonces.contains(&value) {
+            Err(ReplayDetectedError)
+        } else {
+            nonces.insert(value.clone());
+            Ok(value)
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("nonce was already used")]
+pub struct ReplayDetectedError;
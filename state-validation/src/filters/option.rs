@@ -0,0 +1,57 @@
+use crate::StateFilter;
+
+/// Passes if there is a value, outputting it.
+///
+/// To run a filter only if there is a value, use [`Optional`].
+pub struct Required;
+
+impl<State, T> StateFilter<State, Option<T>> for Required {
+    type ValidOutput = T;
+    type Error = MissingError;
+    fn filter(_state: &State, value: Option<T>) -> Result<Self::ValidOutput, Self::Error> {
+        value.ok_or(MissingError)
+    }
+}
+
+/// Runs `F` on the value if there is one, passing `None` through.
+///
+/// To fail on `None` instead, use [`Required`].
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{StateFilter, filters::Optional};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// struct UserStorage(HashSet<u32>);
+/// struct UserExists;
+/// impl StateFilter<UserStorage, u32> for UserExists {
+///     type ValidOutput = u32;
+///     type Error = UserDoesNotExistError;
+///     fn filter(state: &UserStorage, user_id: u32) -> Result<u32, Self::Error> {
+///         state.0.contains(&user_id).then_some(user_id).ok_or(UserDoesNotExistError)
+///     }
+/// }
+///
+/// let users = UserStorage(HashSet::from([0]));
+/// assert_eq!(Optional::<UserExists>::filter(&users, None).unwrap(), None);
+/// assert_eq!(Optional::<UserExists>::filter(&users, Some(0)).unwrap(), Some(0));
+/// assert!(Optional::<UserExists>::filter(&users, Some(1)).is_err());
+/// ```
+pub struct Optional<F>(std::marker::PhantomData<F>);
+
+impl<State, Input, F: StateFilter<State, Input>> StateFilter<State, Option<Input>> for Optional<F> {
+    type ValidOutput = Option<F::ValidOutput>;
+    type Error = F::Error;
+    fn filter(state: &State, value: Option<Input>) -> Result<Self::ValidOutput, Self::Error> {
+        value.map(|value| F::filter(state, value)).transpose()
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected a value, but was missing")]
+pub struct MissingError;
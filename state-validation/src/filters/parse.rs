@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use crate::StateFilter;
+
+/// Parses the string into `T`, usually as the first filter of a chain of domain validation.
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{Condition, StateFilter, filters::ParseFilter};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # struct InvalidUserIDError;
+/// # impl std::error::Error for InvalidUserIDError {}
+/// # impl std::fmt::Display for InvalidUserIDError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid user ID")
+/// #     }
+/// # }
+/// #[derive(Debug, PartialEq, Eq, Hash)]
+/// struct UserID(u128);
+/// impl std::str::FromStr for UserID {
+///     type Err = InvalidUserIDError;
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         u128::from_str_radix(s, 16).map(UserID).map_err(|_| InvalidUserIDError)
+///     }
+/// }
+/// struct UserStorage(HashSet<UserID>);
+/// struct UserExists;
+/// impl StateFilter<UserStorage, UserID> for UserExists {
+///     type ValidOutput = UserID;
+///     type Error = UserDoesNotExistError;
+///     fn filter(state: &UserStorage, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+///         state.0.contains(&user_id).then_some(user_id).ok_or(UserDoesNotExistError)
+///     }
+/// }
+///
+/// type ParseUser = (
+///     Condition<String, ParseFilter<UserID>>,
+///     Condition<UserID, UserExists>,
+/// );
+/// let users = UserStorage(HashSet::from([UserID(0xa1)]));
+/// assert_eq!(ParseUser::filter(&users, "a1".to_string()).unwrap(), UserID(0xa1));
+/// assert!(ParseUser::filter(&users, "b2".to_string()).is_err());
+/// assert!(ParseUser::filter(&users, "not hex".to_string()).is_err());
+/// ```
+pub struct ParseFilter<T>(std::marker::PhantomData<T>);
+
+impl<State, T: FromStr<Err: std::error::Error>> StateFilter<State, String> for ParseFilter<T> {
+    type ValidOutput = T;
+    type Error = T::Err;
+    fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+        value.parse()
+    }
+}
+impl<State, T: FromStr<Err: std::error::Error>> StateFilter<State, &str> for ParseFilter<T> {
+    type ValidOutput = T;
+    type Error = T::Err;
+    fn filter(_state: &State, value: &str) -> Result<Self::ValidOutput, Self::Error> {
+        value.parse()
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+
+use crate::StateFilterMut;
+
+/// Decides whether another request may be made.
+pub trait RateLimitPolicy {
+    /// Records a request made at `now`,
+    /// or returns how long to wait before the next request is permitted.
+    fn try_acquire(&mut self, now: Instant) -> Result<(), Duration>;
+}
+
+/// Permits bursts of up to `capacity` requests, refilling at a steady rate.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// A full bucket.
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        TokenBucket {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            refill_per_second,
+            last_refill: Instant::now(),
+        }
+    }
+}
+
+impl RateLimitPolicy for TokenBucket {
+    fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else if self.refill_per_second > 0.0 {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_second,
+            ))
+        } else {
+            Err(Duration::MAX)
+        }
+    }
+}
+
+/// Projects the rate limit of a key, such as a user, out of the state.
+pub trait StateRateLimits<State, Key, Policy: RateLimitPolicy> {
+    fn rate_limit<'a>(state: &'a mut State, key: &Key) -> &'a mut Policy;
+    /// The current time, which may be overridden to use a clock held in the state.
+    fn now(_state: &State) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Passes if the key is under its rate limit, recording the request.
+/// ```
+/// # use std::{collections::HashMap, time::{Duration, Instant}};
+/// # use state_validation::{StateFilterMut, filters::{RateLimit, StateRateLimits, TokenBucket}};
+/// struct Limits {
+///     now: Instant,
+///     buckets: HashMap<u32, TokenBucket>,
+/// }
+/// struct PerUser;
+/// impl StateRateLimits<Limits, u32, TokenBucket> for PerUser {
+///     fn rate_limit<'a>(state: &'a mut Limits, user: &u32) -> &'a mut TokenBucket {
+///         state.buckets.entry(*user).or_insert_with(|| TokenBucket::new(2, 0.001))
+///     }
+///     fn now(state: &Limits) -> Instant {
+///         state.now
+///     }
+/// }
+///
+/// let mut limits = Limits { now: Instant::now(), buckets: HashMap::new() };
+/// assert!(RateLimit::<PerUser>::filter(&mut limits, 0).is_ok());
+/// assert!(RateLimit::<PerUser>::filter(&mut limits, 0).is_ok());
+/// let error = RateLimit::<PerUser>::filter(&mut limits, 0).unwrap_err();
+/// assert!(error.retry_after.as_secs() > 0);
+/// assert!(RateLimit::<PerUser>::filter(&mut limits, 1).is_ok());
+/// limits.now += Duration::from_secs(1000);
+/// assert!(RateLimit::<PerUser>::filter(&mut limits, 0).is_ok());
+/// ```
+pub struct RateLimit<Getter, Policy = TokenBucket>(std::marker::PhantomData<(Getter, Policy)>);
+
+impl<State, Key, Policy: RateLimitPolicy, Getter: StateRateLimits<State, Key, Policy>>
+    StateFilterMut<State, Key> for RateLimit<Getter, Policy>
+{
+    type ValidOutput = Key;
+    type Error = RateLimitedError;
+    fn filter(state: &mut State, value: Key) -> Result<Self::ValidOutput, Self::Error> {
+        let now = Getter::now(state);
+        Getter::rate_limit(state, &value)
+            .try_acquire(now)
+            .map(|()| value)
+            .map_err(|retry_after| RateLimitedError { retry_after })
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("rate limited, retry after {retry_after:?}")]
+pub struct RateLimitedError {
+    pub retry_after: Duration,
+}
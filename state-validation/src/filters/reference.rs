@@ -0,0 +1,79 @@
+use crate::StateFilter;
+
+/// Looks up the entity a foreign key references, for [`ReferencesExist`].
+pub trait StateReference<State> {
+    /// The name of the reference, used in the error when it is missing.
+    const NAME: &'static str;
+    type Key;
+    type Entity;
+    fn find(state: &State, key: &Self::Key) -> Option<Self::Entity>;
+}
+
+/// Passes if every key references an existing entity, outputting the entities found.
+///
+/// Takes a tuple of [`StateReference`]s and a tuple of their keys,
+/// to validate all the foreign keys of an input in one step.
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{StateFilter, filters::{ReferencesExist, StateReference}};
+/// struct Shop {
+///     users: HashMap<u32, String>,
+///     products: HashMap<u32, String>,
+/// }
+/// struct UserRef;
+/// impl StateReference<Shop> for UserRef {
+///     const NAME: &'static str = "user";
+///     type Key = u32;
+///     type Entity = String;
+///     fn find(shop: &Shop, user_id: &u32) -> Option<String> {
+///         shop.users.get(user_id).cloned()
+///     }
+/// }
+/// struct ProductRef;
+/// impl StateReference<Shop> for ProductRef {
+///     const NAME: &'static str = "product";
+///     type Key = u32;
+///     type Entity = String;
+///     fn find(shop: &Shop, product_id: &u32) -> Option<String> {
+///         shop.products.get(product_id).cloned()
+///     }
+/// }
+///
+/// let shop = Shop {
+///     users: HashMap::from([(0, "admin".to_string())]),
+///     products: HashMap::from([(7, "book".to_string())]),
+/// };
+/// type OrderReferences = ReferencesExist<(UserRef, ProductRef)>;
+/// let (user, product) = OrderReferences::filter(&shop, (0, 7)).unwrap();
+/// assert_eq!((user.as_str(), product.as_str()), ("admin", "book"));
+/// let error = OrderReferences::filter(&shop, (0, 8)).unwrap_err();
+/// assert_eq!(error.reference, "product");
+/// ```
+pub struct ReferencesExist<Getters>(std::marker::PhantomData<Getters>);
+
+macro_rules! impl_references_exist {
+    ($(($G:ident, $key:ident)),*) => {
+        impl<State, $($G: StateReference<State>),*> StateFilter<State, ($($G::Key,)*)>
+            for ReferencesExist<($($G,)*)>
+        {
+            type ValidOutput = ($($G::Entity,)*);
+            type Error = MissingReferenceError;
+            fn filter(
+                state: &State,
+                ($($key,)*): ($($G::Key,)*),
+            ) -> Result<Self::ValidOutput, Self::Error> {
+                Ok(($(
+                    $G::find(state, &$key).ok_or(MissingReferenceError { reference: $G::NAME })?,
+                )*))
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_references_exist, 1, 8, G, key);
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("referenced {reference} does not exist")]
+pub struct MissingReferenceError {
+    /// The [`StateReference::NAME`] of the missing reference.
+    pub reference: &'static str,
+}
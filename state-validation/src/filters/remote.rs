@@ -0,0 +1,96 @@
+use crate::StateFilterAsync;
+
+/// A client to a remote service, such as an HTTP or gRPC client.
+pub trait RemoteClient<ResourceId> {
+    type Resource;
+    /// An error talking to the service, such as a timeout.
+    type Error: std::error::Error;
+    /// Fetches the resource, or `None` if it does not exist.
+    fn fetch(
+        &self,
+        id: ResourceId,
+    ) -> impl Future<Output = Result<Option<Self::Resource>, Self::Error>>;
+}
+
+/// Passes if the resource exists in the remote service,
+/// outputting the fetched resource.
+///
+/// The client is taken from the state with [`AsRef`].
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{StateFilterAsync, filters::{RemoteClient, RemoteExists, RemoteExistsError}};
+/// # #[derive(Debug)]
+/// # struct TimeoutError;
+/// # impl std::error::Error for TimeoutError {}
+/// # impl std::fmt::Display for TimeoutError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "timed out")
+/// #     }
+/// # }
+/// # fn block_on<F: Future>(future: F) -> F::Output {
+/// #     let mut future = std::pin::pin!(future);
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// struct UserClient {
+///     users: HashMap<u32, String>,
+/// }
+/// impl RemoteClient<u32> for UserClient {
+///     type Resource = String;
+///     type Error = TimeoutError;
+///     async fn fetch(&self, id: u32) -> Result<Option<String>, TimeoutError> {
+///         Ok(self.users.get(&id).cloned())
+///     }
+/// }
+/// struct AppState {
+///     users: UserClient,
+/// }
+/// impl AsRef<UserClient> for AppState {
+///     fn as_ref(&self) -> &UserClient {
+///         &self.users
+///     }
+/// }
+///
+/// let state = AppState {
+///     users: UserClient { users: HashMap::from([(0, "admin".to_string())]) },
+/// };
+/// let username = block_on(RemoteExists::<UserClient>::filter(&state, 0)).unwrap();
+/// assert_eq!(username, "admin");
+/// let error = block_on(RemoteExists::<UserClient>::filter(&state, 1)).unwrap_err();
+/// assert!(matches!(error, RemoteExistsError::NotFound));
+/// assert!(!error.is_transient());
+/// ```
+pub struct RemoteExists<Client>(std::marker::PhantomData<Client>);
+
+impl<State: AsRef<Client>, Client: RemoteClient<ResourceId>, ResourceId>
+    StateFilterAsync<State, ResourceId> for RemoteExists<Client>
+{
+    type ValidOutput = Client::Resource;
+    type Error = RemoteExistsError<Client::Error>;
+    async fn filter(state: &State, value: ResourceId) -> Result<Self::ValidOutput, Self::Error> {
+        match state.as_ref().fetch(value).await {
+            Ok(Some(resource)) => Ok(resource),
+            Ok(None) => Err(RemoteExistsError::NotFound),
+            Err(error) => Err(RemoteExistsError::Transient(error)),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RemoteExistsError<E: std::error::Error> {
+    #[error("resource does not exist")]
+    NotFound,
+    /// The service could not be reached, so trying again may succeed.
+    #[error(transparent)]
+    Transient(E),
+}
+
+impl<E: std::error::Error> RemoteExistsError<E> {
+    pub fn is_transient(&self) -> bool {
+        matches!(self, RemoteExistsError::Transient(_))
+    }
+}
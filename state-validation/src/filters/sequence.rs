@@ -0,0 +1,148 @@
+use crate::{StateFilter, StateFilterMut};
+
+/// Projects the last seen sequence value out of the state.
+pub trait StateLastSeen<State, T> {
+    fn last_seen(state: &mut State) -> &mut T;
+}
+
+/// Passes if the sequence value is greater than the last seen value, recording it as the last seen.
+///
+/// With `ALLOW_EQUAL`, a value equal to the last seen value also passes,
+/// for at-least-once delivery where the same value may be redelivered.
+/// ```
+/// # use state_validation::{StateFilterMut, filters::{MonotonicIncreasing, StateLastSeen}};
+/// struct Stream {
+///     last_sequence: u64,
+/// }
+/// struct LastSequence;
+/// impl StateLastSeen<Stream, u64> for LastSequence {
+///     fn last_seen(state: &mut Stream) -> &mut u64 {
+///         &mut state.last_sequence
+///     }
+/// }
+///
+/// let mut stream = Stream { last_sequence: 0 };
+/// assert!(MonotonicIncreasing::<LastSequence>::filter(&mut stream, 1).is_ok());
+/// let error = MonotonicIncreasing::<LastSequence>::filter(&mut stream, 1).unwrap_err();
+/// assert_eq!((error.last_seen, error.got), (1, 1));
+/// assert_eq!(error.to_string(), "sequence value 1 is out of order, expected greater than 1");
+/// assert!(MonotonicIncreasing::<LastSequence, true>::filter(&mut stream, 1).is_ok());
+/// let error = MonotonicIncreasing::<LastSequence, true>::filter(&mut stream, 0).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "sequence value 0 is out of order, expected greater than or equal to 1",
+/// );
+/// assert!(MonotonicIncreasing::<LastSequence>::filter(&mut stream, 5).is_ok());
+/// assert_eq!(stream.last_sequence, 5);
+/// ```
+pub struct MonotonicIncreasing<Getter, const ALLOW_EQUAL: bool = false>(
+    std::marker::PhantomData<Getter>,
+);
+
+impl<
+    State,
+    T: PartialOrd + Clone + std::fmt::Debug,
+    Getter: StateLastSeen<State, T>,
+    const ALLOW_EQUAL: bool,
+> StateFilterMut<State, T> for MonotonicIncreasing<Getter, ALLOW_EQUAL>
+{
+    type ValidOutput = T;
+    type Error = OutOfOrderError<T>;
+    fn filter(state: &mut State, value: T) -> Result<Self::ValidOutput, Self::Error> {
+        let last_seen = Getter::last_seen(state);
+        if value > *last_seen || (ALLOW_EQUAL && value == *last_seen) {
+            *last_seen = value.clone();
+            Ok(value)
+        } else {
+            Err(OutOfOrderError {
+                last_seen: last_seen.clone(),
+                got: value,
+                allow_equal: ALLOW_EQUAL,
+            })
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "sequence value {got:?} is out of order, expected greater than {}{last_seen:?}",
+    if *.allow_equal { "or equal to " } else { "" },
+)]
+pub struct OutOfOrderError<T: std::fmt::Debug> {
+    pub last_seen: T,
+    pub got: T,
+    /// Whether a value equal to the last seen value would have passed.
+    pub allow_equal: bool,
+}
+
+/// Passes if the elements are in non-decreasing order, such as a time-ordered batch of events.
+/// ```
+/// # use state_validation::{StateFilter, filters::{Sorted, UnsortedError}};
+/// assert_eq!(Sorted::filter(&(), Vec::<u32>::new()), Ok(vec![]));
+/// assert_eq!(Sorted::filter(&(), vec![3]), Ok(vec![3]));
+/// assert_eq!(Sorted::filter(&(), vec![1, 2, 2, 5]), Ok(vec![1, 2, 2, 5]));
+/// assert_eq!(Sorted::filter(&(), vec![1, 3, 2]), Err(UnsortedError { index: 2 }));
+/// ```
+pub struct Sorted;
+
+impl<State, T: Ord> StateFilter<State, Vec<T>> for Sorted {
+    type ValidOutput = Vec<T>;
+    type Error = UnsortedError;
+    fn filter(_state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        match value.windows(2).position(|pair| pair[0] > pair[1]) {
+            Some(i) => Err(UnsortedError { index: i + 1 }),
+            None => Ok(value),
+        }
+    }
+}
+
+/// Projects the key elements are sorted by, for [`SortedBy`].
+pub trait SortKey<T> {
+    type Key: Ord;
+    fn key(value: &T) -> Self::Key;
+}
+
+/// Same as [`Sorted`], but compares the keys `K` projects out of the elements.
+/// ```
+/// # use state_validation::{StateFilter, filters::{SortKey, SortedBy, UnsortedError}};
+/// struct Event {
+///     timestamp: u64,
+///     name: &'static str,
+/// }
+/// struct ByTimestamp;
+/// impl SortKey<Event> for ByTimestamp {
+///     type Key = u64;
+///     fn key(event: &Event) -> u64 {
+///         event.timestamp
+///     }
+/// }
+///
+/// let events = vec![Event { timestamp: 1, name: "login" }, Event { timestamp: 4, name: "logout" }];
+/// assert_eq!(SortedBy::<ByTimestamp>::filter(&(), events).unwrap()[1].name, "logout");
+/// assert!(SortedBy::<ByTimestamp>::filter(&(), Vec::new()).is_ok());
+/// assert!(SortedBy::<ByTimestamp>::filter(&(), vec![Event { timestamp: 4, name: "logout" }]).is_ok());
+/// let events = vec![Event { timestamp: 4, name: "logout" }, Event { timestamp: 1, name: "login" }];
+/// assert_eq!(SortedBy::<ByTimestamp>::filter(&(), events).err(), Some(UnsortedError { index: 1 }));
+/// ```
+pub struct SortedBy<K>(std::marker::PhantomData<K>);
+
+impl<State, T, K: SortKey<T>> StateFilter<State, Vec<T>> for SortedBy<K> {
+    type ValidOutput = Vec<T>;
+    type Error = UnsortedError;
+    fn filter(_state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        match value
+            .windows(2)
+            .position(|pair| K::key(&pair[0]) > K::key(&pair[1]))
+        {
+            Some(i) => Err(UnsortedError { index: i + 1 }),
+            None => Ok(value),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("element {index} is out of order")]
+pub struct UnsortedError {
+    /// The first element that is less than the element before it.
+    pub index: usize,
+}
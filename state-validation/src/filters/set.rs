@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::StateFilter;
+
+/// Projects a set out of the state, for [`InSet`] and [`NotInSet`] to check against.
+pub trait StateSet<State, T: 'static> {
+    fn set(state: &State) -> &HashSet<T>;
+}
+
+/// Passes if the input is in the set `Getter` projects out of the state,
+/// such as a country code that must be one of the allowed countries.
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{StateFilter, filters::{InSet, NotInSet, StateSet}};
+/// struct Config {
+///     reserved_usernames: HashSet<String>,
+/// }
+/// struct ReservedUsernames;
+/// impl StateSet<Config, String> for ReservedUsernames {
+///     fn set(config: &Config) -> &HashSet<String> {
+///         &config.reserved_usernames
+///     }
+/// }
+///
+/// let config = Config { reserved_usernames: HashSet::from(["admin".to_string()]) };
+/// assert!(NotInSet::<ReservedUsernames>::filter(&config, "guest".to_string()).is_ok());
+/// let error = NotInSet::<ReservedUsernames>::filter(&config, "admin".to_string()).unwrap_err();
+/// assert_eq!(error.0, "admin");
+/// assert!(InSet::<ReservedUsernames>::filter(&config, "admin".to_string()).is_ok());
+/// ```
+pub struct InSet<Getter>(std::marker::PhantomData<Getter>);
+/// Passes if the input is not in the set `Getter` projects out of the state,
+/// such as a username that must not be a reserved word.
+pub struct NotInSet<Getter>(std::marker::PhantomData<Getter>);
+
+impl<State, T: std::hash::Hash + Eq + std::fmt::Debug + 'static, Getter: StateSet<State, T>>
+    StateFilter<State, T> for InSet<Getter>
+{
+    type ValidOutput = T;
+    type Error = NotInSetError<T>;
+    fn filter(state: &State, value: T) -> Result<Self::ValidOutput, Self::Error> {
+        if Getter::set(state).contains(&value) {
+            Ok(value)
+        } else {
+            Err(NotInSetError(value))
+        }
+    }
+}
+impl<State, T: std::hash::Hash + Eq + std::fmt::Debug + 'static, Getter: StateSet<State, T>>
+    StateFilter<State, T> for NotInSet<Getter>
+{
+    type ValidOutput = T;
+    type Error = InSetError<T>;
+    fn filter(state: &State, value: T) -> Result<Self::ValidOutput, Self::Error> {
+        if Getter::set(state).contains(&value) {
+            Err(InSetError(value))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{0:?} is not in the set")]
+pub struct NotInSetError<T: std::fmt::Debug>(pub T);
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{0:?} is in the set")]
+pub struct InSetError<T: std::fmt::Debug>(pub T);
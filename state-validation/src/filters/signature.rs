@@ -0,0 +1,101 @@
+use crate::StateFilter;
+
+/// A key which verifies signatures, implemented with the crypto library of your choice.
+pub trait SignatureVerifier {
+    fn verify(&self, payload: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Looks up the key a signature was made with, for [`VerifySignature`].
+pub trait StateVerifyingKeys<State> {
+    type KeyId;
+    type Key: SignatureVerifier;
+    fn key<'s>(state: &'s State, key_id: &Self::KeyId) -> Option<&'s Self::Key>;
+}
+
+/// A signature, along with the ID of the key it was made with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature<KeyId> {
+    pub key_id: KeyId,
+    pub bytes: Vec<u8>,
+}
+
+/// A payload whose signature was verified, which can only be made by [`VerifySignature`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedPayload<Payload>(Payload);
+
+impl<Payload> VerifiedPayload<Payload> {
+    pub fn into_inner(self) -> Payload {
+        self.0
+    }
+}
+
+impl<Payload> std::ops::Deref for VerifiedPayload<Payload> {
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Passes if the payload was signed by one of the keys `KeyGetter` looks up in the state,
+/// such as to verify webhooks.
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{StateFilter, filters::{Signature, SignatureError, SignatureVerifier, StateVerifyingKeys, VerifySignature}};
+/// // A stand-in for a real signature scheme.
+/// struct XorKey(u8);
+/// impl SignatureVerifier for XorKey {
+///     fn verify(&self, payload: &[u8], signature: &[u8]) -> bool {
+///         signature == [payload.iter().fold(self.0, |a, b| a ^ b)]
+///     }
+/// }
+/// struct Webhooks {
+///     keys: HashMap<String, XorKey>,
+/// }
+/// struct WebhookKeys;
+/// impl StateVerifyingKeys<Webhooks> for WebhookKeys {
+///     type KeyId = String;
+///     type Key = XorKey;
+///     fn key<'s>(state: &'s Webhooks, key_id: &String) -> Option<&'s XorKey> {
+///         state.keys.get(key_id)
+///     }
+/// }
+///
+/// let webhooks = Webhooks { keys: HashMap::from([("github".to_string(), XorKey(7))]) };
+/// let signature = |key_id: &str, byte| Signature { key_id: key_id.to_string(), bytes: vec![byte] };
+/// let payload = VerifySignature::<WebhookKeys>::filter(&webhooks, (b"hi".to_vec(), signature("github", 7 ^ b'h' ^ b'i')))
+///     .unwrap();
+/// assert_eq!(payload.into_inner(), b"hi");
+/// let error = VerifySignature::<WebhookKeys>::filter(&webhooks, (b"hi".to_vec(), signature("github", 0)))
+///     .unwrap_err();
+/// assert_eq!(error, SignatureError::BadSignature);
+/// let error = VerifySignature::<WebhookKeys>::filter(&webhooks, (b"hi".to_vec(), signature("gitlab", 0)))
+///     .unwrap_err();
+/// assert_eq!(error, SignatureError::UnknownKey);
+/// ```
+pub struct VerifySignature<KeyGetter>(std::marker::PhantomData<KeyGetter>);
+
+impl<State, Payload: AsRef<[u8]>, KeyGetter: StateVerifyingKeys<State>>
+    StateFilter<State, (Payload, Signature<KeyGetter::KeyId>)> for VerifySignature<KeyGetter>
+{
+    type ValidOutput = VerifiedPayload<Payload>;
+    type Error = SignatureError;
+    fn filter(
+        state: &State,
+        (payload, signature): (Payload, Signature<KeyGetter::KeyId>),
+    ) -> Result<Self::ValidOutput, Self::Error> {
+        let key = KeyGetter::key(state, &signature.key_id).ok_or(SignatureError::UnknownKey)?;
+        if key.verify(payload.as_ref(), &signature.bytes) {
+            Ok(VerifiedPayload(payload))
+        } else {
+            Err(SignatureError::BadSignature)
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureError {
+    #[error("signature was made with an unknown key")]
+    UnknownKey,
+    #[error("signature does not match the payload")]
+    BadSignature,
+}
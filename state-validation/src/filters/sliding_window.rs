@@ -0,0 +1,117 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::StateFilterMut;
+
+/// The times of the most recent attempts within a window, such as failed logins.
+#[derive(Debug, Clone)]
+pub struct SlidingWindowCounter {
+    limit: usize,
+    window: Duration,
+    attempts: VecDeque<Instant>,
+}
+
+impl SlidingWindowCounter {
+    /// Permits at most `limit` attempts within any `window`.
+    pub fn new(limit: usize, window: Duration) -> Self {
+        SlidingWindowCounter {
+            limit,
+            window,
+            attempts: VecDeque::with_capacity(limit),
+        }
+    }
+    /// The amount of attempts within the window ending at `now`.
+    pub fn count(&self, now: Instant) -> usize {
+        self.attempts
+            .iter()
+            .filter(|&&attempt| now.saturating_duration_since(attempt) < self.window)
+            .count()
+    }
+    /// Records an attempt made at `now`, or returns the amount of attempts within the window
+    /// if there is no room for another one. Attempts that are not permitted are not recorded.
+    pub fn try_record(&mut self, now: Instant) -> Result<(), usize> {
+        while self
+            .attempts
+            .front()
+            .is_some_and(|&attempt| now.saturating_duration_since(attempt) >= self.window)
+        {
+            self.attempts.pop_front();
+        }
+        if self.attempts.len() >= self.limit {
+            Err(self.attempts.len())
+        } else {
+            self.attempts.push_back(now);
+            Ok(())
+        }
+    }
+}
+
+/// Projects the sliding window of a key, such as a user or IP address, out of the state.
+pub trait StateSlidingWindows<State, Key> {
+    fn window<'a>(state: &'a mut State, key: &Key) -> &'a mut SlidingWindowCounter;
+    /// The current time, which may be overridden to use a clock held in the state.
+    fn now(_state: &State) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Passes if the key has made fewer attempts within the window than its limit, recording the attempt.
+/// ```
+/// # use std::{collections::HashMap, time::{Duration, Instant}};
+/// # use state_validation::{StateFilterMut, filters::{SlidingWindow, SlidingWindowCounter, StateSlidingWindows}};
+/// struct Logins {
+///     now: Instant,
+///     failed: HashMap<u32, SlidingWindowCounter>,
+/// }
+/// struct FailedLogins;
+/// impl StateSlidingWindows<Logins, u32> for FailedLogins {
+///     fn window<'a>(state: &'a mut Logins, user: &u32) -> &'a mut SlidingWindowCounter {
+///         state
+///             .failed
+///             .entry(*user)
+///             .or_insert_with(|| SlidingWindowCounter::new(2, Duration::from_secs(60)))
+///     }
+///     fn now(state: &Logins) -> Instant {
+///         state.now
+///     }
+/// }
+///
+/// let mut logins = Logins { now: Instant::now(), failed: HashMap::new() };
+/// assert!(SlidingWindow::<FailedLogins>::filter(&mut logins, 0).is_ok());
+/// logins.now += Duration::from_secs(30);
+/// assert!(SlidingWindow::<FailedLogins>::filter(&mut logins, 0).is_ok());
+/// let error = SlidingWindow::<FailedLogins>::filter(&mut logins, 0).unwrap_err();
+/// assert_eq!((error.count, error.window), (2, Duration::from_secs(60)));
+/// assert!(SlidingWindow::<FailedLogins>::filter(&mut logins, 1).is_ok());
+/// // The first attempt has left the window.
+/// logins.now += Duration::from_secs(30);
+/// assert!(SlidingWindow::<FailedLogins>::filter(&mut logins, 0).is_ok());
+/// ```
+pub struct SlidingWindow<Getter>(std::marker::PhantomData<Getter>);
+
+impl<State, Key, Getter: StateSlidingWindows<State, Key>> StateFilterMut<State, Key>
+    for SlidingWindow<Getter>
+{
+    type ValidOutput = Key;
+    type Error = AbuseDetectedError;
+    fn filter(state: &mut State, value: Key) -> Result<Self::ValidOutput, Self::Error> {
+        let now = Getter::now(state);
+        let window = Getter::window(state, &value);
+        window
+            .try_record(now)
+            .map(|()| value)
+            .map_err(|count| AbuseDetectedError {
+                count,
+                window: window.window,
+            })
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("{count} attempts within {window:?}")]
+pub struct AbuseDetectedError {
+    pub count: usize,
+    pub window: Duration,
+}
@@ -0,0 +1,127 @@
+use crate::StateFilter;
+
+/// Passes if the string is between `MIN` and `MAX` bytes long, inclusive,
+/// such as to fit a database column.
+/// ```
+/// # use state_validation::{StateFilter, filters::{ByteLenInRange, CharLenInRange, ValidUtf8}};
+/// // "héllo" is five characters, but six bytes.
+/// assert!(CharLenInRange::<1, 5>::filter(&(), "héllo").is_ok());
+/// let error = ByteLenInRange::<1, 5>::filter(&(), "héllo").unwrap_err();
+/// assert_eq!(error.len, 6);
+///
+/// let text = ValidUtf8::filter(&(), "héllo".as_bytes().to_vec()).unwrap();
+/// assert_eq!(text, "héllo");
+/// assert!(ValidUtf8::filter(&(), vec![0xff]).is_err());
+/// ```
+pub struct ByteLenInRange<const MIN: usize, const MAX: usize>;
+/// Passes if the string is between `MIN` and `MAX` characters long, inclusive,
+/// such as to limit what a user sees.
+pub struct CharLenInRange<const MIN: usize, const MAX: usize>;
+/// Passes if the bytes are valid UTF-8, outputting them as a string.
+pub struct ValidUtf8;
+
+impl<State, const MIN: usize, const MAX: usize> StateFilter<State, String>
+    for ByteLenInRange<MIN, MAX>
+{
+    type ValidOutput = String;
+    type Error = ByteLenOutOfRangeError;
+    fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+        check_byte_len::<MIN, MAX>(&value).map(|()| value)
+    }
+}
+impl<'a, State, const MIN: usize, const MAX: usize> StateFilter<State, &'a str>
+    for ByteLenInRange<MIN, MAX>
+{
+    type ValidOutput = &'a str;
+    type Error = ByteLenOutOfRangeError;
+    fn filter(_state: &State, value: &'a str) -> Result<Self::ValidOutput, Self::Error> {
+        check_byte_len::<MIN, MAX>(value).map(|()| value)
+    }
+}
+impl<State, const MIN: usize, const MAX: usize> StateFilter<State, String>
+    for CharLenInRange<MIN, MAX>
+{
+    type ValidOutput = String;
+    type Error = CharLenOutOfRangeError;
+    fn filter(_state: &State, value: String) -> Result<Self::ValidOutput, Self::Error> {
+        check_char_len::<MIN, MAX>(&value).map(|()| value)
+    }
+}
+impl<'a, State, const MIN: usize, const MAX: usize> StateFilter<State, &'a str>
+    for CharLenInRange<MIN, MAX>
+{
+    type ValidOutput = &'a str;
+    type Error = CharLenOutOfRangeError;
+    fn filter(_state: &State, value: &'a str) -> Result<Self::ValidOutput, Self::Error> {
+        check_char_len::<MIN, MAX>(value).map(|()| value)
+    }
+}
+impl<State> StateFilter<State, Vec<u8>> for ValidUtf8 {
+    type ValidOutput = String;
+    type Error = InvalidUtf8Error;
+    fn filter(_state: &State, value: Vec<u8>) -> Result<Self::ValidOutput, Self::Error> {
+        String::from_utf8(value).map_err(|e| InvalidUtf8Error {
+            valid_up_to: e.utf8_error().valid_up_to(),
+        })
+    }
+}
+impl<'a, State> StateFilter<State, &'a [u8]> for ValidUtf8 {
+    type ValidOutput = &'a str;
+    type Error = InvalidUtf8Error;
+    fn filter(_state: &State, value: &'a [u8]) -> Result<Self::ValidOutput, Self::Error> {
+        std::str::from_utf8(value).map_err(|e| InvalidUtf8Error {
+            valid_up_to: e.valid_up_to(),
+        })
+    }
+}
+
+fn check_byte_len<const MIN: usize, const MAX: usize>(
+    value: &str,
+) -> Result<(), ByteLenOutOfRangeError> {
+    let len = value.len();
+    if (MIN..=MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(ByteLenOutOfRangeError {
+            len,
+            min: MIN,
+            max: MAX,
+        })
+    }
+}
+fn check_char_len<const MIN: usize, const MAX: usize>(
+    value: &str,
+) -> Result<(), CharLenOutOfRangeError> {
+    let len = value.chars().count();
+    if (MIN..=MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(CharLenOutOfRangeError {
+            len,
+            min: MIN,
+            max: MAX,
+        })
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected between {min} and {max} bytes, but got {len}")]
+pub struct ByteLenOutOfRangeError {
+    pub len: usize,
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected between {min} and {max} characters, but got {len}")]
+pub struct CharLenOutOfRangeError {
+    pub len: usize,
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid UTF-8 after the first {valid_up_to} bytes")]
+pub struct InvalidUtf8Error {
+    pub valid_up_to: usize,
+}
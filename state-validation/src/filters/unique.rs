@@ -0,0 +1,133 @@
+use std::hash::BuildHasher;
+
+use crate::StateFilter;
+
+/// Projects the existing keys out of the state, for [`Unique`] to check against.
+pub trait StateKeys<State, Key> {
+    fn keys<'a>(state: &'a State) -> impl Iterator<Item = &'a Key>
+    where
+        Key: 'a;
+}
+
+/// Passes if the input key is not already one of the keys `Getter` projects out of the state,
+/// such as an email that must not be used by another user.
+/// ```
+/// # use state_validation::{StateFilter, filters::{StateKeys, Unique}};
+/// struct User {
+///     username: String,
+/// }
+/// struct Usernames;
+/// impl StateKeys<Vec<User>, String> for Usernames {
+///     fn keys<'a>(users: &'a Vec<User>) -> impl Iterator<Item = &'a String>
+///     where
+///         String: 'a,
+///     {
+///         users.iter().map(|user| &user.username)
+///     }
+/// }
+///
+/// let users = vec![User { username: "admin".to_string() }];
+/// assert!(Unique::<Usernames, String>::filter(&users, "guest".to_string()).is_ok());
+/// assert!(Unique::<Usernames, String>::filter(&users, "admin".to_string()).is_err());
+///
+/// // Keys may also be borrowed, such as lines of a request body.
+/// struct Lines;
+/// impl<'s> StateKeys<Vec<&'s str>, &'s str> for Lines {
+///     fn keys<'a>(lines: &'a Vec<&'s str>) -> impl Iterator<Item = &'a &'s str>
+///     where
+///         &'s str: 'a,
+///     {
+///         lines.iter()
+///     }
+/// }
+/// let body = String::from("admin\nguest");
+/// let lines: Vec<&str> = body.lines().collect();
+/// assert!(Unique::<Lines, &str>::filter(&lines, &body[..5]).is_err());
+/// ```
+pub struct Unique<Getter, Key>(std::marker::PhantomData<(Getter, Key)>);
+
+impl<State, Getter: StateKeys<State, Key>, Key: PartialEq> StateFilter<State, Key>
+    for Unique<Getter, Key>
+{
+    type ValidOutput = Key;
+    type Error = DuplicateError;
+    fn filter(state: &State, value: Key) -> Result<Self::ValidOutput, Self::Error> {
+        if Getter::keys(state).any(|key| *key == value) {
+            Err(DuplicateError)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("key already exists")]
+pub struct DuplicateError;
+
+/// Passes if every element passes `F`, and no two elements have the same output,
+/// such as a list of tags.
+/// ```
+/// # use state_validation::{StateFilter, filters::{UniqueElements, UniqueElementsError}};
+/// # #[derive(Debug, PartialEq)]
+/// # struct EmptyError;
+/// # impl std::error::Error for EmptyError {}
+/// # impl std::fmt::Display for EmptyError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "empty")
+/// #     }
+/// # }
+/// struct Tag;
+/// impl<State> StateFilter<State, &str> for Tag {
+///     type ValidOutput = String;
+///     type Error = EmptyError;
+///     fn filter(_state: &State, value: &str) -> Result<Self::ValidOutput, Self::Error> {
+///         let tag = value.trim().to_lowercase();
+///         if tag.is_empty() { Err(EmptyError) } else { Ok(tag) }
+///     }
+/// }
+///
+/// let tags = UniqueElements::<Tag>::filter(&(), vec!["rust", "async"]).unwrap();
+/// assert_eq!(tags, vec!["rust", "async"]);
+/// assert_eq!(
+///     UniqueElements::<Tag>::filter(&(), vec!["rust", "Rust "]),
+///     Err(UniqueElementsError::Duplicate { index: 1 }),
+/// );
+/// assert_eq!(
+///     UniqueElements::<Tag>::filter(&(), vec!["rust", " "]),
+///     Err(UniqueElementsError::Filter { index: 1, error: EmptyError }),
+/// );
+/// ```
+pub struct UniqueElements<F>(std::marker::PhantomData<F>);
+
+impl<State, T, F: StateFilter<State, T>> StateFilter<State, Vec<T>> for UniqueElements<F>
+where
+    F::ValidOutput: std::hash::Hash + Eq,
+{
+    type ValidOutput = Vec<F::ValidOutput>;
+    type Error = UniqueElementsError<F::Error>;
+    fn filter(state: &State, value: Vec<T>) -> Result<Self::ValidOutput, Self::Error> {
+        let hasher = std::hash::RandomState::new();
+        // Indices of the outputs by their hash, so outputs are compared only on a collision.
+        let mut seen = std::collections::HashMap::<u64, Vec<usize>>::new();
+        let mut outputs = Vec::with_capacity(value.len());
+        for (index, element) in value.into_iter().enumerate() {
+            let output = F::filter(state, element)
+                .map_err(|error| UniqueElementsError::Filter { index, error })?;
+            let same_hash = seen.entry(hasher.hash_one(&output)).or_default();
+            if same_hash.iter().any(|&i| outputs[i] == output) {
+                return Err(UniqueElementsError::Duplicate { index });
+            }
+            same_hash.push(index);
+            outputs.push(output);
+        }
+        Ok(outputs)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum UniqueElementsError<E: std::error::Error> {
+    #[error("element {index} is invalid: {error}")]
+    Filter { index: usize, error: E },
+    #[error("element {index} is a duplicate")]
+    Duplicate { index: usize },
+}
@@ -0,0 +1,42 @@
+/// Builds a value from a tuple of its fields.
+///
+/// Implemented by the structs `StateFilterConversion` generates to hold the outputs of a chain,
+/// whose names are not meant to be written out,
+/// so intermediate values of a chain can be built, such as in tests.
+/// ```
+/// # use state_validation::{Condition, FromFields, StateFilter, StateFilterConversion, StateFilterInputConversion};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// # struct UserID(usize);
+/// # struct User(UserID);
+/// # struct Username(String);
+/// # struct UserExists;
+/// # impl<State> StateFilter<State, UserID> for UserExists {
+/// #     type ValidOutput = User;
+/// #     type Error = UserDoesNotExistError;
+/// #     fn filter(_state: &State, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+/// #         Ok(User(user_id))
+/// #     }
+/// # }
+/// #[derive(StateFilterConversion)]
+/// struct UserWithUsername {
+///     #[conversion(User)]
+///     user_id: UserID,
+///     username: Username,
+/// }
+/// type Output = <Condition<UserID, UserExists> as StateFilter<(), UserWithUsername>>::ValidOutput;
+///
+/// // Build the output of `UserExists` without running it.
+/// let output = Output::from_fields((User(UserID(0)), Username("ADMIN".to_string())));
+/// let (user, _remainder): (User, _) = output.split_take();
+/// # let _ = user;
+/// ```
+pub trait FromFields<Fields> {
+    fn from_fields(fields: Fields) -> Self;
+}
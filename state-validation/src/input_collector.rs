@@ -1,4 +1,4 @@
-use crate::StateFilter;
+use crate::{StateFilter, ValidationReport};
 
 pub struct CollectedInputs<State, Inputs: Iterator> {
     inputs: Inputs,
@@ -9,9 +9,39 @@ impl<State, Inputs: Iterator> CollectedInputs<State, Inputs> {
     pub fn new(inputs: Inputs) -> Self {
         CollectedInputs {
             inputs,
-            _m: std::marker::PhantomData::default(),
+            _m: std::marker::PhantomData,
         }
     }
+    /// Appends the inputs of another collection, such as one collected from a different part of the state.
+    /// ```
+    /// # use state_validation::{CollectInputsExt, StateFilter};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// let pending_users = vec![1, 2].into_iter().collect_inputs::<()>();
+    /// let pending_orders = vec![0, 3].into_iter().collect_inputs::<()>();
+    /// let valid: Vec<_> = pending_users.chain(pending_orders).fits_iter::<NonZero>(&()).collect();
+    /// assert_eq!(valid, vec![1, 2, 3]);
+    /// ```
+    pub fn chain<Other: Iterator<Item = Inputs::Item>>(
+        self,
+        other: CollectedInputs<State, Other>,
+    ) -> CollectedInputs<State, std::iter::Chain<Inputs, Other>> {
+        CollectedInputs::new(self.inputs.chain(other.inputs))
+    }
     /// Do all the inputs pass the filter without error?
     pub fn fits_all<F: StateFilter<State, Inputs::Item>>(self, state: &State) -> bool {
         self.inputs
@@ -33,8 +63,88 @@ impl<State, Inputs: Iterator> CollectedInputs<State, Inputs> {
             .into_iter()
             .filter_map(|input| F::filter(state, input).ok())
     }
+    /// Folds the outputs of the inputs that pass the filter without error.
+    pub fn validate_fold<F: StateFilter<State, Inputs::Item>, B>(
+        self,
+        state: &State,
+        init: B,
+        f: impl FnMut(B, F::ValidOutput) -> B,
+    ) -> B {
+        self.fits_iter::<F>(state).fold(init, f)
+    }
+    /// Summary of how many inputs pass the filter, along with the errors of those that did not.
+    pub fn report<F: StateFilter<State, Inputs::Item>>(
+        self,
+        state: &State,
+    ) -> ValidationReport<F::Error> {
+        ValidationReport::fold::<State, Inputs::Item, F>(state, self.inputs)
+    }
+    /// The outputs of all the inputs, or the error of the first input that fails the filter,
+    /// without filtering the rest.
+    /// ```
+    /// # use state_validation::{CollectInputsExt, StateFilter};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// let valid = vec![1, 2].into_iter().collect_inputs::<()>().all_valid_or_err::<NonZero>(&());
+    /// assert_eq!(valid.unwrap(), vec![1, 2]);
+    /// let invalid = vec![1, 0].into_iter().collect_inputs::<()>().all_valid_or_err::<NonZero>(&());
+    /// assert!(invalid.is_err());
+    /// ```
+    pub fn all_valid_or_err<F: StateFilter<State, Inputs::Item>>(
+        self,
+        state: &State,
+    ) -> Result<Vec<F::ValidOutput>, F::Error> {
+        self.inputs.map(|input| F::filter(state, input)).collect()
+    }
 }
 
 pub trait InputCollector<State, Input> {
     fn collect_inputs(state: &State) -> CollectedInputs<State, impl Iterator<Item = Input>>;
 }
+
+/// Collects any iterator into [`CollectedInputs`].
+///
+/// The state cannot be inferred from the iterator, so it is given with a turbofish.
+/// ```
+/// # use state_validation::{CollectInputsExt, StateFilter};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = u32;
+///     type Error = ZeroError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+///     }
+/// }
+///
+/// let ids = vec![1, 2, 3];
+/// assert!(ids.into_iter().collect_inputs::<()>().fits_all::<NonZero>(&()));
+/// ```
+pub trait CollectInputsExt: Iterator + Sized {
+    fn collect_inputs<State>(self) -> CollectedInputs<State, Self> {
+        CollectedInputs::new(self)
+    }
+}
+
+impl<I: Iterator> CollectInputsExt for I {}
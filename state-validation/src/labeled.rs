@@ -0,0 +1,92 @@
+use crate::StateFilter;
+
+/// A value tagged with a `Label` type.
+///
+/// Produced by labeled conversions: `#[conversion(as = Foo, name = "owner")]`.
+/// Two conversions of a field into the same type are told apart by their label,
+/// and a [`Condition`](crate::Condition) picks one by using [`WithLabel`] on its filter.
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{Condition, Labeled, StateFilter, StateFilterConversion, WithLabel};
+/// # #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// # struct UserID(usize);
+/// # #[derive(Clone)]
+/// # struct User(UserID);
+/// # struct UserStorage(HashMap<UserID, User>);
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// struct UserExists;
+/// impl StateFilter<UserStorage, UserID> for UserExists {
+///     type ValidOutput = User;
+///     type Error = UserDoesNotExistError;
+///     fn filter(state: &UserStorage, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+///         state.0.get(&user_id).cloned().ok_or(UserDoesNotExistError)
+///     }
+/// }
+///
+/// #[derive(StateFilterConversion)]
+/// struct Transfer {
+///     #[conversion(as = User, name = "owner")]
+///     #[conversion(as = User, name = "editor")]
+///     user_id: UserID,
+/// }
+///
+/// let mut users = HashMap::new();
+/// users.insert(UserID(0), User(UserID(0)));
+/// let users = UserStorage(users);
+///
+/// let owner = Condition::<UserID, WithLabel<UserExists, TransferOwner>>::filter(
+///     &users,
+///     Transfer { user_id: UserID(0) },
+/// )
+/// .unwrap();
+/// let owner: Labeled<User, TransferOwner> = owner.user_id;
+/// assert!(owner.into_inner().0 == UserID(0));
+///
+/// let editor = Condition::<UserID, WithLabel<UserExists, TransferEditor>>::filter(
+///     &users,
+///     Transfer { user_id: UserID(0) },
+/// )
+/// .unwrap();
+/// let _: Labeled<User, TransferEditor> = editor.user_id;
+/// ```
+pub struct Labeled<T, Label>(pub T, std::marker::PhantomData<Label>);
+
+impl<T, Label> Labeled<T, Label> {
+    pub fn new(value: T) -> Self {
+        Labeled(value, std::marker::PhantomData)
+    }
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+impl<T, Label> std::ops::Deref for Labeled<T, Label> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T, Label> std::ops::DerefMut for Labeled<T, Label> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Labels the output of `F`, selecting the labeled conversion it is combined into.
+pub struct WithLabel<F, Label>(std::marker::PhantomData<(F, Label)>);
+
+impl<State, Input, F: StateFilter<State, Input>, Label> StateFilter<State, Input>
+    for WithLabel<F, Label>
+{
+    type ValidOutput = Labeled<F::ValidOutput, Label>;
+    type Error = F::Error;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, value).map(Labeled::new)
+    }
+}
@@ -194,6 +194,8 @@
 //! struct RemoveAdmin;
 //! impl ValidAction<UserStorage, UserID> for RemoveAdmin {
 //!     // To chain filters, use `Condition`.
+//!     // Every element of the tuple must be a `Condition`,
+//!     // while a single filter, like `UserExists`, can be used on its own.
 //!     type Filter = (
 //!         //       <Input,  Filter>
 //!         Condition<UserID, UserExists>,
@@ -444,6 +446,297 @@
 //! ```
 //! This way, the compiler can differentiate between a `String` and a `Username`.
 //!
+//! Every way of splitting the struct is generated, which grows quickly with the amount of fields.
+//! If only a few splits are ever used, list them with `targets` to generate only those:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion};
+//! # #[derive(Clone, Copy)]
+//! # struct UserID(usize);
+//! # struct User(UserID);
+//! # struct Username(String);
+//! # #[derive(Debug)]
+//! # struct UserDoesNotExistError;
+//! # impl std::error::Error for UserDoesNotExistError {}
+//! # impl std::fmt::Display for UserDoesNotExistError {
+//! #    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #        write!(f, "user does not exist")
+//! #     }
+//! # }
+//! # struct UserExists;
+//! # impl<State> StateFilter<State, UserID> for UserExists {
+//! #     type ValidOutput = User;
+//! #     type Error = UserDoesNotExistError;
+//! #     fn filter(state: &State, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+//! #         Ok(User(user_id))
+//! #     }
+//! # }
+//! #[derive(StateFilterConversion)]
+//! #[conversion(targets(UserID, (User, Username)))]
+//! struct UserWithUsername {
+//!     #[conversion(User)]
+//!     user_id: UserID,
+//!     username: Username,
+//! }
+//! let user = Condition::<UserID, UserExists>::filter(
+//!     &(),
+//!     UserWithUsername { user_id: UserID(0), username: Username("ADMIN".to_string()) },
+//! )
+//! .unwrap();
+//! # let _: User = user.user_id;
+//! ```
+//! Here, `UserWithUsername` can only be split into `UserID`, and after it becomes a `User`,
+//! into `(User, Username)`. The targets may be any type a field can be converted to with `conversion`.
+//!
+//! To keep compile times in check, a struct with more than six fields to split is rejected:
+//! ```compile_fail
+//! # use state_validation::StateFilterConversion;
+//! #[derive(StateFilterConversion)]
+//! struct Registration {
+//!     a: u8, b: u16, c: u32, d: u64, e: i8, f: i16, g: i32,
+//! }
+//! ```
+//! Fields which no filter needs can be left out of the splits with `skip`.
+//! They are kept in every remainder, and carried over to the output of the chain:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion};
+//! #[derive(StateFilterConversion)]
+//! #[conversion(targets(u8))]
+//! struct Registration {
+//!     a: u8, b: u16, c: u32, d: u64, e: i8, f: i16,
+//!     #[conversion(skip)]
+//!     g: i32,
+//! }
+//! let registration = Condition::<u8, ()>::filter(
+//!     &(),
+//!     Registration { a: 1, b: 2, c: 3, d: 4, e: 5, f: 6, g: 7 },
+//! )
+//! .unwrap();
+//! assert_eq!((registration.a, registration.g), (1, 7));
+//! ```
+//! Otherwise, raise the limit with `max_fields`, along with `targets` to generate only the splits
+//! that are used:
+//! ```
+//! # use state_validation::StateFilterConversion;
+//! #[derive(StateFilterConversion)]
+//! #[conversion(max_fields = 7)]
+//! #[conversion(targets(u8, (u8, u16)))]
+//! struct Registration {
+//!     a: u8, b: u16, c: u32, d: u64, e: i8, f: i16, g: i32,
+//! }
+//! ```
+//!
+//! A field of `Option<T>` is split out as `Option<T>`. Filter it with [`filters::Optional`] to validate
+//! the value only when present, keeping `None` in the output, or with [`filters::Required`]
+//! to split out the value, failing the chain when it is missing.
+//! Every conversion of an `Option` field may also be held wrapped in `Option`,
+//! so `#[conversion(ValidEmail)]` lets the field become a `ValidEmail` or an `Option<ValidEmail>`:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion, StateFilterTwoChainError};
+//! # use state_validation::filters::{MissingError, Optional, Required};
+//! # #[derive(Debug)]
+//! # struct InvalidEmailError;
+//! # impl std::error::Error for InvalidEmailError {}
+//! # impl std::fmt::Display for InvalidEmailError {
+//! #    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #        write!(f, "invalid email")
+//! #     }
+//! # }
+//! # #[derive(Debug, PartialEq)]
+//! # struct Email(String);
+//! # #[derive(Debug, PartialEq)]
+//! # struct ValidEmail(String);
+//! # struct ValidateEmail;
+//! # impl<State> StateFilter<State, Email> for ValidateEmail {
+//! #     type ValidOutput = ValidEmail;
+//! #     type Error = InvalidEmailError;
+//! #     fn filter(state: &State, email: Email) -> Result<Self::ValidOutput, Self::Error> {
+//! #         if email.0.contains('@') { Ok(ValidEmail(email.0)) } else { Err(InvalidEmailError) }
+//! #     }
+//! # }
+//! #[derive(StateFilterConversion)]
+//! struct SignUp {
+//!     #[conversion(Email)]
+//!     #[conversion(ValidEmail)]
+//!     email: Option<Email>,
+//! }
+//! let email = || Some(Email("admin@example.com".to_string()));
+//!
+//! // The email may be left out.
+//! type OptionalEmail = Condition<Option<Email>, Optional<ValidateEmail>>;
+//! let sign_up = OptionalEmail::filter(&(), SignUp { email: email() }).unwrap();
+//! assert_eq!(sign_up.email, Some(ValidEmail("admin@example.com".to_string())));
+//! let sign_up = OptionalEmail::filter(&(), SignUp { email: None }).unwrap();
+//! assert_eq!(sign_up.email, None);
+//!
+//! // The email must be given.
+//! type RequiredEmail = (
+//!     Condition<Option<Email>, Required>,
+//!     Condition<Email, ValidateEmail>,
+//! );
+//! let sign_up = RequiredEmail::filter(&(), SignUp { email: email() }).unwrap();
+//! assert_eq!(sign_up.email, ValidEmail("admin@example.com".to_string()));
+//! let Err(error) = RequiredEmail::filter(&(), SignUp { email: None }) else { unreachable!() };
+//! assert!(matches!(error, StateFilterTwoChainError::Filter0(MissingError)));
+//! ```
+//!
+//! Inputs which borrow, such as when parsing a request without copying, derive conversions too.
+//! The lifetime is carried over to the outputs of the chain:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion};
+//! # #[derive(Debug)]
+//! # struct EmptyTokenError;
+//! # impl std::error::Error for EmptyTokenError {}
+//! # impl std::fmt::Display for EmptyTokenError {
+//! #    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #        write!(f, "empty token")
+//! #     }
+//! # }
+//! # struct UserID(usize);
+//! # struct User(UserID);
+//! # struct UserExists;
+//! # impl<State> StateFilter<State, UserID> for UserExists {
+//! #     type ValidOutput = User;
+//! #     type Error = std::convert::Infallible;
+//! #     fn filter(state: &State, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+//! #         Ok(User(user_id))
+//! #     }
+//! # }
+//! struct Token<'a>(&'a str);
+//! struct NonEmptyToken;
+//! impl<'a, State> StateFilter<State, Token<'a>> for NonEmptyToken {
+//!     type ValidOutput = Token<'a>;
+//!     type Error = EmptyTokenError;
+//!     fn filter(state: &State, token: Token<'a>) -> Result<Self::ValidOutput, Self::Error> {
+//!         if token.0.is_empty() { Err(EmptyTokenError) } else { Ok(token) }
+//!     }
+//! }
+//! #[derive(StateFilterConversion)]
+//! struct Request<'a> {
+//!     path: &'a str,
+//!     token: Token<'a>,
+//!     #[conversion(User)]
+//!     user_id: UserID,
+//! }
+//!
+//! let body = String::from("GET /users secret");
+//! let request = Request { path: &body[4..10], token: Token(&body[11..]), user_id: UserID(0) };
+//! let request = <(
+//!     Condition<Token<'_>, NonEmptyToken>,
+//!     Condition<UserID, UserExists>,
+//! )>::filter(&(), request)
+//! .unwrap();
+//! assert_eq!((request.path, request.token.0), ("/users", "secret"));
+//! # let _: User = request.user_id;
+//! ```
+//!
+//! When a chain does not compile because a split is missing, add `introspect` to list
+//! every type the struct's fields can be split into:
+//! ```
+//! # use state_validation::StateFilterConversion;
+//! # struct UserID(usize);
+//! # struct User(UserID);
+//! # struct Username(String);
+//! #[derive(StateFilterConversion)]
+//! #[conversion(introspect)]
+//! struct UserWithUsername {
+//!     #[conversion(User)]
+//!     user_id: UserID,
+//!     username: Option<Username>,
+//! }
+//! // Generic types are written as `stringify!` spaces them.
+//! assert_eq!(UserWithUsername::CONVERSIONS, ["UserID", "User", "Option < Username >"]);
+//! ```
+//!
+//! The structs holding the values in the middle of a chain can derive traits with `derive`,
+//! such as `Clone` to duplicate them:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion};
+//! # #[derive(Clone)]
+//! # struct UserID(usize);
+//! # #[derive(Clone)]
+//! # struct User(UserID);
+//! # #[derive(Clone)]
+//! # struct Username(String);
+//! # struct UserExists;
+//! # impl<State> StateFilter<State, UserID> for UserExists {
+//! #     type ValidOutput = User;
+//! #     type Error = std::convert::Infallible;
+//! #     fn filter(state: &State, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+//! #         Ok(User(user_id))
+//! #     }
+//! # }
+//! #[derive(StateFilterConversion)]
+//! #[conversion(derive(Clone))]
+//! struct UserWithUsername {
+//!     #[conversion(User)]
+//!     user_id: UserID,
+//!     username: Username,
+//! }
+//! let output = Condition::<UserID, UserExists>::filter(
+//!     &(),
+//!     UserWithUsername { user_id: UserID(0), username: Username("ADMIN".to_string()) },
+//! )
+//! .unwrap();
+//! let copy = output.clone();
+//! # let _: (User, User) = (output.user_id, copy.user_id);
+//! ```
+//!
+//! The structs holding the values in the middle of a chain have generated names,
+//! which change as fields are added. Give the ones you refer to a stable name with `name`,
+//! listing the type each field is converted into:
+//! ```
+//! # use state_validation::{Condition, StateFilter, StateFilterConversion};
+//! # struct UserID(usize);
+//! # struct User(UserID);
+//! # struct Username(String);
+//! # struct UserExists;
+//! # impl<State> StateFilter<State, UserID> for UserExists {
+//! #     type ValidOutput = User;
+//! #     type Error = std::convert::Infallible;
+//! #     fn filter(state: &State, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+//! #         Ok(User(user_id))
+//! #     }
+//! # }
+//! #[derive(StateFilterConversion)]
+//! #[conversion(name = "UserWithName", types(User, Username))]
+//! struct UserWithUsername {
+//!     #[conversion(User)]
+//!     user_id: UserID,
+//!     username: Username,
+//! }
+//! fn greet(user: UserWithName) -> String {
+//!     format!("hello, {}", user.username.0)
+//! }
+//! let user = Condition::<UserID, UserExists>::filter(
+//!     &(),
+//!     UserWithUsername { user_id: UserID(0), username: Username("admin".to_string()) },
+//! )
+//! .unwrap();
+//! assert_eq!(greet(user), "hello, admin");
+//! ```
+//!
+//! To narrow an enum down to one of its variants, derive `VariantFilters`,
+//! which generates a filter for each variant that outputs the variant's fields, named after the enum and the variant:
+//! ```
+//! # use state_validation::{StateFilter, VariantFilters};
+//! # #[derive(Debug, PartialEq)]
+//! # struct UserID(usize);
+//! #[derive(VariantFilters)]
+//! enum Event {
+//!     Login(UserID),
+//!     Rename { user_id: UserID, username: String },
+//!     Logout,
+//! }
+//! assert_eq!(EventIsLogin::filter(&(), Event::Login(UserID(0))).unwrap(), UserID(0));
+//! let (user_id, username) = EventIsRename::filter(
+//!     &(),
+//!     Event::Rename { user_id: UserID(0), username: "ADMIN".to_string() },
+//! )
+//! .unwrap();
+//! # assert_eq!((user_id, username.as_str()), (UserID(0), "ADMIN"));
+//! assert!(EventIsLogout::filter(&(), Event::Login(UserID(0))).is_err());
+//! ```
+//!
 //!
 //! The [`StateFilterInputConversion`] and [`StateFilterInputCombination`] traits work together
 //! to allow splitting the input down into its parts and then back together.
@@ -641,6 +934,60 @@
 //! }
 //! ```
 //!
+//! ## Filtering Against a Trait
+//! The state of a [`StateFilter`] must be sized, so a filter cannot be implemented for `dyn Trait`.
+//! To let plugins write filters against an interface rather than a concrete state,
+//! implement the filter for any state implementing the trait.
+//! Implement the trait for `Box<dyn Trait>` too, so the concrete state can also be hidden behind it:
+//! ```
+//! # use std::collections::HashMap;
+//! # use state_validation::{StateFilter, Validator};
+//! # #[derive(Debug)]
+//! # struct UserDoesNotExistError;
+//! # impl std::error::Error for UserDoesNotExistError {}
+//! # impl std::fmt::Display for UserDoesNotExistError {
+//! #    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #        write!(f, "user does not exist")
+//! #     }
+//! # }
+//! # #[derive(Clone)]
+//! # struct User(String);
+//! trait UserStore {
+//!     fn get(&self, id: u32) -> Option<User>;
+//! }
+//! impl<T: UserStore + ?Sized> UserStore for Box<T> {
+//!     fn get(&self, id: u32) -> Option<User> {
+//!         (**self).get(id)
+//!     }
+//! }
+//!
+//! // Written by a plugin, knowing only `UserStore`.
+//! struct UserExists;
+//! impl<S: UserStore> StateFilter<S, u32> for UserExists {
+//!     type ValidOutput = User;
+//!     type Error = UserDoesNotExistError;
+//!     fn filter(state: &S, id: u32) -> Result<Self::ValidOutput, Self::Error> {
+//!         state.get(id).ok_or(UserDoesNotExistError)
+//!     }
+//! }
+//!
+//! // The concrete state of the application.
+//! struct AppState {
+//!     users: HashMap<u32, User>,
+//! }
+//! impl UserStore for AppState {
+//!     fn get(&self, id: u32) -> Option<User> {
+//!         self.users.get(&id).cloned()
+//!     }
+//! }
+//!
+//! let state = AppState { users: HashMap::from([(0, User("admin".to_string()))]) };
+//! assert!(UserExists::filter(&state, 0).is_ok());
+//! let state: Box<dyn UserStore> = Box::new(state);
+//! let validator = Validator::<_, _, UserExists>::try_new(state, 0).unwrap();
+//! assert_eq!(validator.valid_output().0, "admin");
+//! ```
+//!
 //! ## Soundness Rules
 //! [`Validator::try_new`] takes ownership of the `state` to disallow consecutive
 //! [`Validator::execute`] calls because an action is assumed to mutate the `state`.
@@ -655,25 +1002,187 @@
 //! Having no more than eight implementations is arbitrary because having more than eight filters is unlikely.
 //! There is no reason not to implement more in the future, if more than eight filters are required.
 
+/// Invokes `$m!` once for every chain error, with the error type, variant and filter index of each filter,
+/// so a trait forwarded through chain errors is implemented for all of them in one place.
+macro_rules! for_each_chain_error {
+    ($m:ident) => {
+        $m!(StateFilterTwoChainError, (E0, Filter0, 0), (E1, Filter1, 1));
+        $m!(
+            StateFilterThreeChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2)
+        );
+        $m!(
+            StateFilterFourChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2),
+            (E3, Filter3, 3)
+        );
+        $m!(
+            StateFilterFiveChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2),
+            (E3, Filter3, 3),
+            (E4, Filter4, 4)
+        );
+        $m!(
+            StateFilterSixChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2),
+            (E3, Filter3, 3),
+            (E4, Filter4, 4),
+            (E5, Filter5, 5)
+        );
+        $m!(
+            StateFilterSevenChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2),
+            (E3, Filter3, 3),
+            (E4, Filter4, 4),
+            (E5, Filter5, 5),
+            (E6, Filter6, 6)
+        );
+        $m!(
+            StateFilterEightChainError,
+            (E0, Filter0, 0),
+            (E1, Filter1, 1),
+            (E2, Filter2, 2),
+            (E3, Filter3, 3),
+            (E4, Filter4, 4),
+            (E5, Filter5, 5),
+            (E6, Filter6, 6),
+            (E7, Filter7, 7)
+        );
+    };
+}
+
+mod accumulate;
 mod action;
+#[cfg(feature = "anyhow")]
+mod anyhow_compat;
+mod async_filter;
+mod capability;
+mod combinator;
 mod condition;
+mod ctx;
+#[cfg(feature = "serde")]
+mod denial;
+mod describe;
+mod error_code;
+mod field_path;
+mod from_fields;
 #[cfg(feature = "dynamic")]
 pub mod dynamic;
+pub mod filters;
 #[cfg(feature = "input_collector")]
 mod input_collector;
+mod labeled;
+#[cfg(feature = "log")]
+mod log_compat;
+mod macros;
+mod normalize;
+mod partial_validator;
+#[cfg(feature = "policy")]
+mod policy;
+pub mod prelude;
+mod probe;
+#[cfg(feature = "receipt")]
+mod receipt;
+mod report;
+#[cfg(feature = "serde")]
+mod serde_compat;
+mod size_limit;
 mod state_filter;
+mod timed;
+#[cfg(feature = "tower")]
+mod tower_compat;
+mod validating_iter;
+mod validator_guard;
+mod validator_mut;
+mod validator_ref;
+mod value_filter;
+mod versioned;
+#[cfg(feature = "validator-compat")]
+mod validator_compat;
+pub use accumulate::*;
 pub use action::*;
+pub use async_filter::*;
+pub use capability::*;
+pub use combinator::*;
 pub use condition::*;
+pub use ctx::*;
+#[cfg(feature = "serde")]
+pub use denial::*;
+pub use describe::*;
+pub use error_code::*;
+pub use field_path::*;
+pub use from_fields::*;
 #[cfg(feature = "input_collector")]
 pub use input_collector::*;
+pub use labeled::*;
+pub use normalize::*;
+pub use partial_validator::*;
+#[cfg(feature = "policy")]
+pub use policy::*;
+pub use probe::*;
+#[cfg(feature = "receipt")]
+pub use receipt::*;
+pub use report::*;
+pub use size_limit::*;
 pub use state_filter::*;
+pub use timed::*;
+#[cfg(feature = "tower")]
+pub use tower_compat::*;
+pub use validating_iter::*;
+pub use validator_guard::*;
+pub use validator_mut::*;
+pub use validator_ref::*;
+pub use value_filter::*;
+pub use versioned::*;
+#[cfg(feature = "validator-compat")]
+pub use validator_compat::*;
 #[cfg(feature = "derive")]
 pub use state_validation_derive::*;
 
+/// Holds the state along with the valid output of the filter.
+///
+/// A validator is [`Send`] whenever `State` and the valid output are,
+/// so it may be held across `.await` points; the input and filter don't matter, as they aren't stored.
+/// ```
+/// # use std::rc::Rc;
+/// # use state_validation::{StateFilter, Validator};
+/// # #[derive(Debug)]
+/// # struct NeverError;
+/// # impl std::fmt::Display for NeverError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "never")
+/// #     }
+/// # }
+/// # impl std::error::Error for NeverError {}
+/// struct SendState;
+/// // `Rc` is not `Send`, but it is consumed by the filter.
+/// struct Unwrap;
+/// impl StateFilter<SendState, Rc<u32>> for Unwrap {
+///     type ValidOutput = u32;
+///     type Error = NeverError;
+///     fn filter(_: &SendState, value: Rc<u32>) -> Result<u32, NeverError> {
+///         Ok(*value)
+///     }
+/// }
+///
+/// fn assert_send<T: Send>(_: &T) {}
+/// let validator = Validator::<_, _, Unwrap>::try_new(SendState, Rc::new(5)).unwrap();
+/// assert_send(&validator);
+/// ```
 pub struct Validator<State, Input, Filter: StateFilter<State, Input>> {
     state: State,
     value: Filter::ValidOutput,
-    _p: std::marker::PhantomData<(Input, Filter)>,
+    _p: std::marker::PhantomData<fn() -> (Input, Filter)>,
 }
 
 impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Filter> {
@@ -685,22 +1194,488 @@ impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Fi
             Ok(value) => Ok(Validator {
                 state,
                 value,
-                _p: std::marker::PhantomData::default(),
+                _p: std::marker::PhantomData,
             }),
             Err(error) => Err(ValidationError { state, error }),
         }
     }
+    /// Same as [`Validator::try_new`], but the context is attached to the error on failure.
+    pub fn try_new_with_context<C: std::fmt::Debug + std::fmt::Display>(
+        state: State,
+        input: Input,
+        context: C,
+    ) -> Result<Self, ValidationError<State, ContextualError<C, Filter::Error>>> {
+        Validator::try_new(state, input).map_err(|e| ValidationError {
+            state: e.state,
+            error: ContextualError {
+                context,
+                error: e.error,
+            },
+        })
+    }
+    /// Produces the input from the state, and then validates it.
+    pub fn try_new_from<ProducerError: std::error::Error>(
+        state: State,
+        producer: impl FnOnce(&State) -> Result<Input, ProducerError>,
+    ) -> Result<Self, ValidationError<State, ProducedInputError<ProducerError, Filter::Error>>>
+    {
+        match producer(&state) {
+            Ok(input) => Validator::try_new(state, input).map_err(|e| ValidationError {
+                state: e.state,
+                error: ProducedInputError::Filter(e.error),
+            }),
+            Err(error) => Err(ValidationError {
+                state,
+                error: ProducedInputError::Producer(error),
+            }),
+        }
+    }
     pub fn state(&self) -> &State {
         &self.state
     }
     pub fn valid_output(&self) -> &Filter::ValidOutput {
         &self.value
     }
-    pub fn execute<Action: ValidAction<State, Input, Filter = Filter>>(
+    /// Clones the valid output, keeping the validator to execute an action later.
+    pub fn valid_output_cloned(&self) -> Filter::ValidOutput
+    where
+        Filter::ValidOutput: Clone,
+    {
+        self.value.clone()
+    }
+    /// Checks one more condition on the valid output, returning the state and `error` if it fails.
+    /// ```
+    /// # use state_validation::{StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # #[derive(Debug)]
+    /// # struct OverLimitError;
+    /// # impl std::error::Error for OverLimitError {}
+    /// # impl std::fmt::Display for OverLimitError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "over limit")
+    /// #     }
+    /// # }
+    /// struct NonZero;
+    /// impl<State> StateFilter<State, u32> for NonZero {
+    ///     type ValidOutput = u32;
+    ///     type Error = ZeroError;
+    ///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// let limit = 10;
+    /// let validator = Validator::<_, _, NonZero>::try_new((), 20).unwrap();
+    /// assert!(validator.ensure_or(|amount| *amount <= limit, OverLimitError).is_err());
+    /// ```
+    pub fn ensure_or<E: std::error::Error>(
+        self,
+        predicate: impl FnOnce(&Filter::ValidOutput) -> bool,
+        error: E,
+    ) -> Result<Self, ValidationError<State, E>> {
+        if predicate(&self.value) {
+            Ok(self)
+        } else {
+            Err(ValidationError {
+                state: self.state,
+                error,
+            })
+        }
+    }
+    pub fn execute<Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>>>(
+        self,
+        valid_action: Action,
+    ) -> Action::Output {
+        valid_action.with_valid_input(self.state, Action::Filter::same_output(self.value))
+    }
+    /// Executes an action, running the hooks before and after it.
+    /// ```
+    /// # use state_validation::{ExecuteHooks, StateFilter, ValidAction, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// struct Deposit;
+    /// impl ValidAction<u32, u32> for Deposit {
+    ///     type Filter = NonZero;
+    ///     type Output = u32;
+    ///     fn with_valid_input(self, balance: u32, amount: u32) -> Self::Output {
+    ///         balance + amount
+    ///     }
+    /// }
+    /// struct AuditLog(Vec<String>);
+    /// impl ExecuteHooks<u32, u32, u32> for AuditLog {
+    ///     fn before(&mut self, balance: &u32, amount: &u32) {
+    ///         self.0.push(format!("depositing {amount} into {balance}"));
+    ///     }
+    ///     fn after(&mut self, balance: &u32) {
+    ///         self.0.push(format!("balance is now {balance}"));
+    ///     }
+    /// }
+    ///
+    /// let mut log = AuditLog(Vec::new());
+    /// let balance = Validator::try_new(10, 5).unwrap().execute_hooked(Deposit, &mut log);
+    /// assert_eq!(balance, 15);
+    /// assert_eq!(log.0, ["depositing 5 into 10", "balance is now 15"]);
+    /// ```
+    pub fn execute_hooked<
+        Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>>,
+    >(
         self,
         valid_action: Action,
+        hooks: &mut impl ExecuteHooks<State, Filter::ValidOutput, Action::Output>,
     ) -> Action::Output {
-        valid_action.with_valid_input(self.state, self.value)
+        hooks.before(&self.state, &self.value);
+        let output =
+            valid_action.with_valid_input(self.state, Action::Filter::same_output(self.value));
+        hooks.after(&output);
+        output
+    }
+    /// Executes a closure in place of a [`ValidAction`], for actions that are not reused.
+    /// ```
+    /// # use state_validation::{StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// struct NonZero;
+    /// impl<State> StateFilter<State, u32> for NonZero {
+    ///     type ValidOutput = u32;
+    ///     type Error = ZeroError;
+    ///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// let validator = Validator::<_, _, NonZero>::try_new(vec![1], 2).unwrap();
+    /// let state = validator.execute_fn(|mut state, value| {
+    ///     state.push(value);
+    ///     state
+    /// });
+    /// assert_eq!(state, vec![1, 2]);
+    /// ```
+    pub fn execute_fn<Output>(
+        self,
+        action: impl FnOnce(State, Filter::ValidOutput) -> Output,
+    ) -> Output {
+        action(self.state, self.value)
+    }
+    /// Executes an action with a clone of the valid output,
+    /// returning the valid output alongside the action's output.
+    pub fn execute_keep_valid<
+        Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>>,
+    >(
+        self,
+        valid_action: Action,
+    ) -> (Action::Output, Filter::ValidOutput)
+    where
+        Filter::ValidOutput: Clone,
+    {
+        let output = valid_action
+            .with_valid_input(self.state, Action::Filter::same_output(self.value.clone()));
+        (output, self.value)
+    }
+    /// Executes an action on a snapshot of the state, and then validates the resulting state with `PostFilter`.
+    /// If it fails, the mutated state is discarded, and the snapshot is returned with the error.
+    /// ```
+    /// # use state_validation::{StateFilter, ValidAction, Validator};
+    /// # #[derive(Debug)]
+    /// # struct InvalidError;
+    /// # impl std::error::Error for InvalidError {}
+    /// # impl std::fmt::Display for InvalidError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "invalid")
+    /// #     }
+    /// # }
+    /// # #[derive(Clone, Debug)]
+    /// struct Balance(i32);
+    /// struct Positive;
+    /// impl<State> StateFilter<State, i32> for Positive {
+    ///     type ValidOutput = i32;
+    ///     type Error = InvalidError;
+    ///     fn filter(_state: &State, value: i32) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if value > 0 { Ok(value) } else { Err(InvalidError) }
+    ///     }
+    /// }
+    /// struct NotOverdrawn;
+    /// impl StateFilter<Balance, ()> for NotOverdrawn {
+    ///     type ValidOutput = ();
+    ///     type Error = InvalidError;
+    ///     fn filter(state: &Balance, _: ()) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if state.0 >= 0 { Ok(()) } else { Err(InvalidError) }
+    ///     }
+    /// }
+    /// struct Withdraw;
+    /// impl ValidAction<Balance, i32> for Withdraw {
+    ///     type Filter = Positive;
+    ///     type Output = Balance;
+    ///     fn with_valid_input(self, state: Balance, amount: i32) -> Self::Output {
+    ///         Balance(state.0 - amount)
+    ///     }
+    /// }
+    ///
+    /// let validator = Validator::try_new(Balance(10), 15).unwrap();
+    /// let error = validator
+    ///     .execute_transactional::<_, NotOverdrawn, _>(Withdraw, ())
+    ///     .unwrap_err();
+    /// assert_eq!(error.state.0, 10);
+    /// ```
+    pub fn execute_transactional<
+        Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>, Output = State>,
+        PostFilter: StateFilter<State, PostInput>,
+        PostInput,
+    >(
+        self,
+        valid_action: Action,
+        post_input: PostInput,
+    ) -> Result<State, ValidationError<State, PostFilter::Error>>
+    where
+        State: Clone,
+    {
+        let snapshot = self.state.clone();
+        let state =
+            valid_action.with_valid_input(self.state, Action::Filter::same_output(self.value));
+        match PostFilter::filter(&state, post_input) {
+            Ok(_) => Ok(state),
+            Err(error) => Err(ValidationError {
+                state: snapshot,
+                error,
+            }),
+        }
+    }
+    /// Validates the input and executes an action whose output is a `Result`,
+    /// joining the error of the filter and the error of the action into one.
+    ///
+    /// The state is dropped if validation fails;
+    /// use [`Validator::try_new`] and [`Validator::execute`] to get it back.
+    /// ```
+    /// # use state_validation::{ExecuteError, StateFilter, ValidAction, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # #[derive(Debug)]
+    /// # struct OverflowError;
+    /// # impl std::error::Error for OverflowError {}
+    /// # impl std::fmt::Display for OverflowError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "overflow")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u8> for NonZero {
+    /// #     type ValidOutput = u8;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u8) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// struct Deposit;
+    /// impl ValidAction<u8, u8> for Deposit {
+    ///     type Filter = NonZero;
+    ///     type Output = Result<u8, OverflowError>;
+    ///     fn with_valid_input(self, balance: u8, amount: u8) -> Self::Output {
+    ///         balance.checked_add(amount).ok_or(OverflowError)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Validator::<_, _, NonZero>::execute_flatten(10, 5, Deposit).unwrap(), 15);
+    /// let error = Validator::<_, _, NonZero>::execute_flatten(10, 0, Deposit).unwrap_err();
+    /// assert!(matches!(error, ExecuteError::Validation(ZeroError)));
+    /// let error = Validator::<_, _, NonZero>::execute_flatten(250, 10, Deposit).unwrap_err();
+    /// assert!(matches!(error, ExecuteError::Action(OverflowError)));
+    /// ```
+    pub fn execute_flatten<
+        Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>, Output = Result<T, E>>,
+        T,
+        E: std::error::Error,
+    >(
+        state: State,
+        input: Input,
+        valid_action: Action,
+    ) -> Result<T, ExecuteError<Filter::Error, E>> {
+        Validator::<State, Input, Filter>::try_new(state, input)
+            .map_err(|e| ExecuteError::Validation(e.error))?
+            .execute(valid_action)
+            .map_err(ExecuteError::Action)
+    }
+    /// Executes an action which may abort, returning the state if it does.
+    /// ```
+    /// # use state_validation::{AbortableAction, StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = ZeroError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// struct Withdraw;
+    /// impl AbortableAction<u32, u32> for Withdraw {
+    ///     type Filter = NonZero;
+    ///     type Output = u32;
+    ///     fn with_valid_input(self, balance: u32, amount: u32) -> Result<Self::Output, u32> {
+    ///         balance.checked_sub(amount).ok_or(balance)
+    ///     }
+    /// }
+    ///
+    /// let validator = Validator::<_, _, NonZero>::try_new(10, 15).unwrap();
+    /// assert_eq!(validator.execute_abortable(Withdraw), Err(10));
+    /// ```
+    pub fn execute_abortable<
+        Action: AbortableAction<State, Input, Filter: SameFilter<State, Input, Filter>>,
+    >(
+        self,
+        abortable_action: Action,
+    ) -> Result<Action::Output, State> {
+        abortable_action.with_valid_input(self.state, Action::Filter::same_output(self.value))
+    }
+    /// Executes an action which only borrows the valid output, keeping it in the validator.
+    /// ```
+    /// # use state_validation::{StateFilter, ValidActionBorrowValid, Validator};
+    /// # #[derive(Debug)]
+    /// # struct EmptyError;
+    /// # impl std::error::Error for EmptyError {}
+    /// # impl std::fmt::Display for EmptyError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "empty")
+    /// #     }
+    /// # }
+    /// struct NonEmpty;
+    /// impl<State> StateFilter<State, Vec<String>> for NonEmpty {
+    ///     type ValidOutput = Vec<String>;
+    ///     type Error = EmptyError;
+    ///     fn filter(_state: &State, value: Vec<String>) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if value.is_empty() { Err(EmptyError) } else { Ok(value) }
+    ///     }
+    /// }
+    /// struct Summarize;
+    /// impl ValidActionBorrowValid<(), Vec<String>> for Summarize {
+    ///     type Filter = NonEmpty;
+    ///     type Output = String;
+    ///     fn with_valid_input(self, _state: &(), lines: &Vec<String>) -> Self::Output {
+    ///         format!("{} lines", lines.len())
+    ///     }
+    /// }
+    ///
+    /// let lines = vec!["a".to_string(), "b".to_string()];
+    /// let validator = Validator::<_, _, NonEmpty>::try_new((), lines).unwrap();
+    /// assert_eq!(validator.execute_borrow_valid(Summarize), "2 lines");
+    /// assert_eq!(validator.valid_output()[1], "b");
+    /// ```
+    pub fn execute_borrow_valid<
+        Action: ValidActionBorrowValid<State, Input, Filter: SameFilter<State, Input, Filter>>,
+    >(
+        &self,
+        valid_action: Action,
+    ) -> Action::Output {
+        valid_action.with_valid_input(&self.state, Action::Filter::same_output_ref(&self.value))
+    }
+}
+
+/// Whether the input passes the filter, without taking the state,
+/// so the same input can be checked again and again, such as on every keystroke of a form.
+/// ```
+/// # use state_validation::{StateFilter, would_validate};
+/// # #[derive(Debug)]
+/// # struct TakenError;
+/// # impl std::error::Error for TakenError {}
+/// # impl std::fmt::Display for TakenError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "username is taken")
+/// #     }
+/// # }
+/// struct Usernames(Vec<String>);
+/// struct UsernameFree;
+/// impl StateFilter<Usernames, &str> for UsernameFree {
+///     type ValidOutput = ();
+///     type Error = TakenError;
+///     fn filter(state: &Usernames, username: &str) -> Result<(), Self::Error> {
+///         if state.0.iter().any(|taken| taken == username) { Err(TakenError) } else { Ok(()) }
+///     }
+/// }
+///
+/// let usernames = Usernames(vec!["admin".to_string()]);
+/// assert!(!would_validate::<_, _, UsernameFree>(&usernames, "admin"));
+/// assert!(would_validate::<_, _, UsernameFree>(&usernames, "admin2"));
+/// ```
+pub fn would_validate<State, Input, Filter: StateFilter<State, Input>>(
+    state: &State,
+    input: Input,
+) -> bool {
+    Filter::filter(state, input).is_ok()
+}
+
+impl<'a, State, Input, F: StateFilterRef<State, Input>> Validator<State, &'a Input, ByRef<F>> {
+    /// Validates without taking ownership of the input.
+    /// ```
+    /// # use state_validation::{ByRef, StateFilterRef, Validator};
+    /// # #[derive(Debug)]
+    /// # struct UntitledError;
+    /// # impl std::error::Error for UntitledError {}
+    /// # impl std::fmt::Display for UntitledError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "document has no title")
+    /// #     }
+    /// # }
+    /// struct Document {
+    ///     title: String,
+    ///     body: String,
+    /// }
+    /// struct HasTitle;
+    /// impl<State> StateFilterRef<State, Document> for HasTitle {
+    ///     type ValidOutput = ();
+    ///     type Error = UntitledError;
+    ///     fn filter(_state: &State, document: &Document) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if document.title.is_empty() { Err(UntitledError) } else { Ok(()) }
+    ///     }
+    /// }
+    ///
+    /// let document = Document { title: "Title".to_string(), body: String::new() };
+    /// let validator = Validator::<_, _, ByRef<HasTitle>>::try_new_ref((), &document);
+    /// assert!(validator.is_ok());
+    /// assert_eq!(document.title, "Title");
+    /// ```
+    pub fn try_new_ref(
+        state: State,
+        input: &'a Input,
+    ) -> Result<Self, ValidationError<State, F::Error>> {
+        Validator::try_new(state, input)
     }
 }
 
@@ -716,3 +1691,25 @@ impl<State, E: std::error::Error> std::fmt::Debug for ValidationError<State, E>
         std::fmt::Debug::fmt(&self.error, f)
     }
 }
+
+/// Error from [`Validator::execute_flatten`].
+#[derive(thiserror::Error, Debug)]
+pub enum ExecuteError<F: std::error::Error, A: std::error::Error> {
+    /// The input did not pass the filter.
+    #[error(transparent)]
+    Validation(F),
+    /// The input passed the filter, but the action failed.
+    #[error(transparent)]
+    Action(A),
+}
+
+/// Error from [`Validator::try_new_from`].
+#[derive(thiserror::Error, Debug)]
+pub enum ProducedInputError<P: std::error::Error, E: std::error::Error> {
+    /// The input could not be produced.
+    #[error(transparent)]
+    Producer(P),
+    /// The input was produced, but did not pass the filter.
+    #[error(transparent)]
+    Filter(E),
+}
@@ -0,0 +1,97 @@
+use crate::{DescribableChain, FilterIndex, StateFilter, ValidationError, Validator};
+
+impl<State, Input, Filter: StateFilter<State, Input> + DescribableChain>
+    Validator<State, Input, Filter>
+where
+    Filter::Error: FilterIndex,
+{
+    /// Same as [`Validator::try_new`], but on failure logs a single warning describing the chain
+    /// and the filter it stopped at, such as
+    /// `chain [UserExists, UserIsAdmin] failed at step 2 (UserIsAdmin): user is not an admin`.
+    /// ```
+    /// # use std::sync::Mutex;
+    /// # use state_validation::{Condition, StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct InvalidError(&'static str);
+    /// # impl std::error::Error for InvalidError {}
+    /// # impl std::fmt::Display for InvalidError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "{}", self.0)
+    /// #     }
+    /// # }
+    /// # struct NonZero;
+    /// # impl<State> StateFilter<State, u32> for NonZero {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = InvalidError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value == 0 { Err(InvalidError("zero")) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// # struct Even;
+    /// # impl<State> StateFilter<State, u32> for Even {
+    /// #     type ValidOutput = u32;
+    /// #     type Error = InvalidError;
+    /// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+    /// #         if value % 2 == 1 { Err(InvalidError("odd")) } else { Ok(value) }
+    /// #     }
+    /// # }
+    /// static LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    /// struct Capture;
+    /// impl log::Log for Capture {
+    ///     fn enabled(&self, _metadata: &log::Metadata) -> bool {
+    ///         true
+    ///     }
+    ///     fn log(&self, record: &log::Record) {
+    ///         LOGS.lock().unwrap().push(format!("{}: {}", record.level(), record.args()));
+    ///     }
+    ///     fn flush(&self) {}
+    /// }
+    /// log::set_logger(&Capture).unwrap();
+    /// log::set_max_level(log::LevelFilter::Warn);
+    ///
+    /// type Chain = (Condition<u32, NonZero>, Condition<u32, Even>);
+    /// assert!(Validator::<_, _, Chain>::try_new_logged((), 2).is_ok());
+    /// assert!(Validator::<_, _, Chain>::try_new_logged((), 3).is_err());
+    /// assert_eq!(
+    ///     *LOGS.lock().unwrap(),
+    ///     ["WARN: chain [NonZero, Even] failed at step 2 (Even): odd"],
+    /// );
+    /// ```
+    pub fn try_new_logged(
+        state: State,
+        input: Input,
+    ) -> Result<Self, ValidationError<State, Filter::Error>> {
+        Self::try_new(state, input).inspect_err(|e| {
+            let names: Vec<_> = Filter::describe()
+                .into_iter()
+                .map(short_type_name)
+                .collect();
+            let index = e.error.filter_index();
+            log::warn!(
+                target: "state_validation",
+                "chain [{}] failed at step {} ({}): {}",
+                names.join(", "),
+                index + 1,
+                names.get(index).map_or("unknown", String::as_str),
+                e.error,
+            );
+        })
+    }
+}
+
+/// Strips the module paths from a type name, such as `app::User<app::Admin>` into `User<Admin>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or_default());
+    short
+}
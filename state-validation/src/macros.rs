@@ -0,0 +1,235 @@
+/// Implements a single [`StateFilterInputConversion`](crate::StateFilterInputConversion)
+/// and its matching [`StateFilterInputCombination`](crate::StateFilterInputCombination),
+/// for when only one split of a struct is needed and the full
+/// [`StateFilterConversion`](crate::StateFilterConversion) derive is too much.
+///
+/// The fields listed after the target are taken out, and the rest are moved into the remainder struct,
+/// which has to be declared with those same field names.
+/// Combining the remainder with the target puts the original struct back together.
+/// ```
+/// # use state_validation::{StateFilterInputCombination, StateFilterInputConversion, impl_conversion};
+/// # struct UserID(usize);
+/// struct UserWithUsername {
+///     user_id: UserID,
+///     username: String,
+/// }
+/// struct UsernameForUserID {
+///     username: String,
+/// }
+/// impl_conversion!(UserWithUsername => UserID { user_id }, remainder UsernameForUserID { username });
+///
+/// let input = UserWithUsername { user_id: UserID(0), username: "ADMIN".to_string() };
+/// let (user_id, remainder) = StateFilterInputConversion::<UserID>::split_take(input);
+/// assert_eq!(user_id.0, 0);
+/// let input = remainder.combine(user_id);
+/// assert_eq!(input.username, "ADMIN");
+/// ```
+/// More than one field is taken out as a tuple: `Input => (A, B) { a, b }, remainder Rest { c }`.
+#[macro_export]
+macro_rules! impl_conversion {
+    (
+        $input:ident => $target:ty { $($field:ident),+ $(,)? },
+        remainder $remainder:ident { $($rest:ident),* $(,)? } $(,)?
+    ) => {
+        impl $crate::StateFilterInputConversion<$target> for $input {
+            type Remainder = $remainder;
+            fn split_take(self) -> ($target, Self::Remainder) {
+                (
+                    ($(self.$field),+),
+                    $remainder {
+                        $($rest: self.$rest),*
+                    },
+                )
+            }
+        }
+        impl $crate::StateFilterInputCombination<$target> for $remainder {
+            type Combined = $input;
+            fn combine(self, ($($field),+): $target) -> Self::Combined {
+                $input {
+                    $($field,)+
+                    $($rest: self.$rest),*
+                }
+            }
+        }
+    };
+}
+
+/// Expands to the valid output of an action's filter,
+/// `<Self::Filter as StateFilter<State, Input>>::ValidOutput`,
+/// for the signature of `with_valid_input`.
+/// ```
+/// # use state_validation::{StateFilter, ValidAction, valid_output};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// # struct UserID(usize);
+/// # struct User(UserID);
+/// # struct UserStorage;
+/// # struct UserExists;
+/// # impl StateFilter<UserStorage, UserID> for UserExists {
+/// #     type ValidOutput = User;
+/// #     type Error = UserDoesNotExistError;
+/// #     fn filter(_state: &UserStorage, user_id: UserID) -> Result<Self::ValidOutput, Self::Error> {
+/// #         Ok(User(user_id))
+/// #     }
+/// # }
+/// struct DeleteUser;
+/// impl ValidAction<UserStorage, UserID> for DeleteUser {
+///     type Filter = UserExists;
+///     type Output = UserStorage;
+///     fn with_valid_input(
+///         self,
+///         state: UserStorage,
+///         _user: valid_output!(Self, UserStorage, UserID),
+///     ) -> Self::Output {
+///         state
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! valid_output {
+    ($action:ty, $state:ty, $input:ty $(,)?) => {
+        <<$action>::Filter as $crate::StateFilter<$state, $input>>::ValidOutput
+    };
+}
+
+/// Declares a flat error enum with a variant for the error of each filter in a chain,
+/// implementing `From` for each of those errors and for the error of the chain,
+/// so [`MapErr`](crate::MapErr) turns the chain's error into it.
+///
+/// The error type of each variant must be different.
+/// ```
+/// # use state_validation::{Condition, MapErr, StateFilter, chain_error};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # struct UserIsNotAdminError;
+/// # impl std::error::Error for UserIsNotAdminError {}
+/// # impl std::fmt::Display for UserIsNotAdminError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user is not an admin")
+/// #     }
+/// # }
+/// # struct UserExists;
+/// # impl<State> StateFilter<State, u32> for UserExists {
+/// #     type ValidOutput = u32;
+/// #     type Error = UserDoesNotExistError;
+/// #     fn filter(_state: &State, user_id: u32) -> Result<u32, Self::Error> {
+/// #         if user_id < 10 { Ok(user_id) } else { Err(UserDoesNotExistError) }
+/// #     }
+/// # }
+/// # struct UserIsAdmin;
+/// # impl<State> StateFilter<State, u32> for UserIsAdmin {
+/// #     type ValidOutput = u32;
+/// #     type Error = UserIsNotAdminError;
+/// #     fn filter(_state: &State, user_id: u32) -> Result<u32, Self::Error> {
+/// #         if user_id == 0 { Ok(user_id) } else { Err(UserIsNotAdminError) }
+/// #     }
+/// # }
+/// chain_error! {
+///     pub enum RemoveAdminError {
+///         UserMissing(UserDoesNotExistError),
+///         NotAdmin(UserIsNotAdminError),
+///     }
+/// }
+/// type RemoveAdmin = MapErr<
+///     (Condition<u32, UserExists>, Condition<u32, UserIsAdmin>),
+///     RemoveAdminError,
+/// >;
+///
+/// assert!(matches!(RemoveAdmin::filter(&(), 20), Err(RemoveAdminError::UserMissing(_))));
+/// let error = RemoveAdmin::filter(&(), 1).unwrap_err();
+/// assert_eq!(error.to_string(), "user is not an admin");
+/// ```
+#[macro_export]
+macro_rules! chain_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident { $($variant:ident($ty:ty)),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        $vis enum $name {
+            $($variant($ty)),+
+        }
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($name::$variant(error) => std::fmt::Display::fmt(error, f)),+
+                }
+            }
+        }
+        impl std::error::Error for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $($name::$variant(error) => std::error::Error::source(error)),+
+                }
+            }
+        }
+        $(
+            impl From<$ty> for $name {
+                fn from(error: $ty) -> Self {
+                    $name::$variant(error)
+                }
+            }
+        )+
+        $crate::chain_error!(@chain $name; $($variant($ty)),+);
+    };
+    (@chain $name:ident; $v0:ident($t0:ty)) => {};
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty)) => {
+        $crate::chain_error!(@from $name StateFilterTwoChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty)) => {
+        $crate::chain_error!(@from $name StateFilterThreeChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty), $v3:ident($t3:ty)) => {
+        $crate::chain_error!(@from $name StateFilterFourChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2), ($v3 Filter3 $t3));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty), $v3:ident($t3:ty),
+        $v4:ident($t4:ty)) => {
+        $crate::chain_error!(@from $name StateFilterFiveChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2), ($v3 Filter3 $t3),
+            ($v4 Filter4 $t4));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty), $v3:ident($t3:ty),
+        $v4:ident($t4:ty), $v5:ident($t5:ty)) => {
+        $crate::chain_error!(@from $name StateFilterSixChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2), ($v3 Filter3 $t3),
+            ($v4 Filter4 $t4), ($v5 Filter5 $t5));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty), $v3:ident($t3:ty),
+        $v4:ident($t4:ty), $v5:ident($t5:ty), $v6:ident($t6:ty)) => {
+        $crate::chain_error!(@from $name StateFilterSevenChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2), ($v3 Filter3 $t3),
+            ($v4 Filter4 $t4), ($v5 Filter5 $t5), ($v6 Filter6 $t6));
+    };
+    (@chain $name:ident; $v0:ident($t0:ty), $v1:ident($t1:ty), $v2:ident($t2:ty), $v3:ident($t3:ty),
+        $v4:ident($t4:ty), $v5:ident($t5:ty), $v6:ident($t6:ty), $v7:ident($t7:ty)) => {
+        $crate::chain_error!(@from $name StateFilterEightChainError;
+            ($v0 Filter0 $t0), ($v1 Filter1 $t1), ($v2 Filter2 $t2), ($v3 Filter3 $t3),
+            ($v4 Filter4 $t4), ($v5 Filter5 $t5), ($v6 Filter6 $t6), ($v7 Filter7 $t7));
+    };
+    (@from $name:ident $chain:ident; $(($variant:ident $filter:ident $ty:ty)),+) => {
+        impl From<$crate::$chain<$($ty),+>> for $name {
+            fn from(error: $crate::$chain<$($ty),+>) -> Self {
+                match error {
+                    $($crate::$chain::$filter(error) => $name::$variant(error)),+
+                }
+            }
+        }
+    };
+}
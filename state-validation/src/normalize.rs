@@ -0,0 +1,43 @@
+use crate::StateFilter;
+
+/// A filter which validates an input by normalizing it in place,
+/// such as trimming whitespace or lowercasing an email.
+///
+/// Use [`Normalize`] to run it as a [`StateFilter`], whose valid output is the normalized input.
+/// ```
+/// # use state_validation::{Normalize, NormalizingFilter, StateFilter};
+/// # #[derive(Debug)]
+/// # struct EmptyEmailError;
+/// # impl std::error::Error for EmptyEmailError {}
+/// # impl std::fmt::Display for EmptyEmailError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "email is empty")
+/// #     }
+/// # }
+/// struct NormalizeEmail;
+/// impl<State> NormalizingFilter<State, String> for NormalizeEmail {
+///     type Error = EmptyEmailError;
+///     fn normalize(_state: &State, email: &mut String) -> Result<(), Self::Error> {
+///         *email = email.trim().to_lowercase();
+///         if email.is_empty() { Err(EmptyEmailError) } else { Ok(()) }
+///     }
+/// }
+///
+/// let email = Normalize::<NormalizeEmail>::filter(&(), "  Admin@Example.com ".to_string());
+/// assert_eq!(email.unwrap(), "admin@example.com");
+/// ```
+pub trait NormalizingFilter<State, Input> {
+    type Error: std::error::Error;
+    fn normalize(state: &State, input: &mut Input) -> Result<(), Self::Error>;
+}
+
+/// Runs the [`NormalizingFilter`] `F` as a [`StateFilter`].
+pub struct Normalize<F>(std::marker::PhantomData<F>);
+
+impl<State, Input, F: NormalizingFilter<State, Input>> StateFilter<State, Input> for Normalize<F> {
+    type ValidOutput = Input;
+    type Error = F::Error;
+    fn filter(state: &State, mut value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::normalize(state, &mut value).map(|()| value)
+    }
+}
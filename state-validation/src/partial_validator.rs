@@ -0,0 +1,100 @@
+use crate::{StateFilter, StateFilterInputCombination, ValidationError};
+
+/// Validates an input piece by piece, for inputs that arrive over several steps.
+///
+/// Each call to [`PartialValidator::feed`] runs a single filter and combines its valid output
+/// with the outputs fed before it, through [`StateFilterInputCombination`].
+/// The type of the combined outputs changes with every step,
+/// so [`PartialValidator::finalize`] only gives back the final input once every piece has been fed.
+/// ```
+/// # use state_validation::{PartialValidator, StateFilter, StateFilterInputCombination};
+/// # #[derive(Debug)]
+/// # struct InvalidError;
+/// # impl std::error::Error for InvalidError {}
+/// # impl std::fmt::Display for InvalidError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "invalid")
+/// #     }
+/// # }
+/// struct Username(String);
+/// struct Age(u8);
+/// struct Signup {
+///     username: Username,
+///     age: Age,
+/// }
+/// impl StateFilterInputCombination<Age> for Username {
+///     type Combined = Signup;
+///     fn combine(self, age: Age) -> Self::Combined {
+///         Signup { username: self, age }
+///     }
+/// }
+/// struct ValidUsername;
+/// impl<State> StateFilter<State, String> for ValidUsername {
+///     type ValidOutput = Username;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, name: String) -> Result<Self::ValidOutput, Self::Error> {
+///         if name.is_empty() { Err(InvalidError) } else { Ok(Username(name)) }
+///     }
+/// }
+/// struct Adult;
+/// impl<State> StateFilter<State, u8> for Adult {
+///     type ValidOutput = Age;
+///     type Error = InvalidError;
+///     fn filter(_state: &State, age: u8) -> Result<Self::ValidOutput, Self::Error> {
+///         if age >= 18 { Ok(Age(age)) } else { Err(InvalidError) }
+///     }
+/// }
+///
+/// let partial = PartialValidator::new(());
+/// let partial = partial.feed::<ValidUsername, _>("admin".to_string()).unwrap();
+/// assert_eq!(partial.valid().0, "admin");
+/// // A failed step gives the validator back, to try again.
+/// let Err(error) = partial.feed::<Adult, _>(12) else { unreachable!() };
+/// let partial = error.state;
+/// let partial = partial.feed::<Adult, _>(30).unwrap();
+/// let ((), signup) = partial.finalize();
+/// assert_eq!(signup.username.0, "admin");
+/// assert_eq!(signup.age.0, 30);
+/// ```
+pub struct PartialValidator<State, Valid = ()> {
+    state: State,
+    valid: Valid,
+}
+
+impl<State> PartialValidator<State> {
+    pub fn new(state: State) -> Self {
+        PartialValidator { state, valid: () }
+    }
+}
+
+impl<State, Valid> PartialValidator<State, Valid> {
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+    /// The outputs fed so far, combined.
+    pub fn valid(&self) -> &Valid {
+        &self.valid
+    }
+    /// Runs `F` on `input` and combines its output with the outputs fed before it.
+    ///
+    /// If the filter fails, the validator is given back in the error, untouched.
+    pub fn feed<F: StateFilter<State, Input>, Input>(
+        self,
+        input: Input,
+    ) -> Result<PartialValidator<State, Valid::Combined>, ValidationError<Self, F::Error>>
+    where
+        Valid: StateFilterInputCombination<F::ValidOutput>,
+    {
+        match F::filter(&self.state, input) {
+            Ok(output) => Ok(PartialValidator {
+                state: self.state,
+                valid: self.valid.combine(output),
+            }),
+            Err(error) => Err(ValidationError { state: self, error }),
+        }
+    }
+    /// Gives back the state along with the combined outputs.
+    pub fn finalize(self) -> (State, Valid) {
+        (self.state, self.valid)
+    }
+}
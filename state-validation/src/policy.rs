@@ -0,0 +1,90 @@
+use crate::StateFilter;
+
+/// The decision of a [`PolicyEngine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Allow,
+    Deny { reason: String },
+}
+
+/// An external engine evaluating authorization policies, such as Open Policy Agent.
+pub trait PolicyEngine {
+    fn evaluate(&self, query: &serde_json::Value) -> Decision;
+}
+
+/// Reads the parts of the state a policy needs to know about.
+pub trait PolicyContext<State> {
+    type Context: serde::Serialize;
+    fn context(state: &State) -> Self::Context;
+}
+
+/// Passes if the engine allows the query, passing the input through.
+///
+/// The query is `{ "input": input, "context": context }`,
+/// with the context read from the state by `Context`.
+/// The engine is taken from the state with [`AsRef`].
+/// ```
+/// # use state_validation::{Decision, PolicyContext, PolicyEngine, PolicyError, PolicyFilter, StateFilter};
+/// struct OwnerOnly;
+/// impl PolicyEngine for OwnerOnly {
+///     fn evaluate(&self, query: &serde_json::Value) -> Decision {
+///         if query["input"] == query["context"] {
+///             Decision::Allow
+///         } else {
+///             Decision::Deny { reason: "not the owner".to_string() }
+///         }
+///     }
+/// }
+/// struct Document {
+///     owner: u32,
+///     engine: OwnerOnly,
+/// }
+/// impl AsRef<OwnerOnly> for Document {
+///     fn as_ref(&self) -> &OwnerOnly {
+///         &self.engine
+///     }
+/// }
+/// struct Owner;
+/// impl PolicyContext<Document> for Owner {
+///     type Context = u32;
+///     fn context(document: &Document) -> u32 {
+///         document.owner
+///     }
+/// }
+///
+/// let document = Document { owner: 7, engine: OwnerOnly };
+/// type CanEdit = PolicyFilter<OwnerOnly, Owner>;
+/// assert_eq!(CanEdit::filter(&document, 7).unwrap(), 7);
+/// let error = CanEdit::filter(&document, 8).unwrap_err();
+/// assert!(matches!(error, PolicyError::Denied { reason } if reason == "not the owner"));
+/// ```
+pub struct PolicyFilter<Engine, Context>(std::marker::PhantomData<(Engine, Context)>);
+
+impl<
+    State: AsRef<Engine>,
+    Input: serde::Serialize,
+    Engine: PolicyEngine,
+    Context: PolicyContext<State>,
+> StateFilter<State, Input> for PolicyFilter<Engine, Context>
+{
+    type ValidOutput = Input;
+    type Error = PolicyError;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let query = serde_json::json!({
+            "input": serde_json::to_value(&value)?,
+            "context": serde_json::to_value(Context::context(state))?,
+        });
+        match state.as_ref().evaluate(&query) {
+            Decision::Allow => Ok(value),
+            Decision::Deny { reason } => Err(PolicyError::Denied { reason }),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PolicyError {
+    #[error("denied by policy: {reason}")]
+    Denied { reason: String },
+    #[error("failed to build the policy query: {0}")]
+    Query(#[from] serde_json::Error),
+}
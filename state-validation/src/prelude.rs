@@ -0,0 +1,16 @@
+//! Re-exports of what most users of this crate need.
+//!
+//! Traits to implement, the [`Validator`] to run them, and the combinators to chain filters.
+//! The more specialized types, such as [`PartialValidator`](crate::PartialValidator)
+//! or the rest of the built-in [`filters`](crate::filters), are imported on their own.
+//! ```
+//! use state_validation::prelude::*;
+//! ```
+
+pub use crate::filters::{Optional, Required};
+pub use crate::{
+    Condition, MapErr, StateFilter, StateFilterInputCombination, StateFilterInputConversion,
+    ValidAction, Validator, WithIntermediate, WithLabel,
+};
+#[cfg(feature = "derive")]
+pub use state_validation_derive::{StateFilterConversion, VariantFilters};
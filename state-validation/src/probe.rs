@@ -0,0 +1,60 @@
+use crate::StateFilter;
+
+/// Runs every filter in a tuple on a clone of the input, reporting which of them pass.
+///
+/// Unlike a chain, which stops at the first failure, every filter is run,
+/// such as to list every action a user is permitted to perform.
+/// ```
+/// # use std::collections::HashSet;
+/// # use state_validation::{Probe, StateFilter};
+/// # #[derive(Debug)]
+/// # struct ForbiddenError;
+/// # impl std::error::Error for ForbiddenError {}
+/// # impl std::fmt::Display for ForbiddenError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "forbidden")
+/// #     }
+/// # }
+/// struct Permissions {
+///     editors: HashSet<u32>,
+///     deleters: HashSet<u32>,
+/// }
+/// struct CanEdit;
+/// impl StateFilter<Permissions, u32> for CanEdit {
+///     type ValidOutput = u32;
+///     type Error = ForbiddenError;
+///     fn filter(state: &Permissions, user: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         state.editors.contains(&user).then_some(user).ok_or(ForbiddenError)
+///     }
+/// }
+/// struct CanDelete;
+/// impl StateFilter<Permissions, u32> for CanDelete {
+///     type ValidOutput = u32;
+///     type Error = ForbiddenError;
+///     fn filter(state: &Permissions, user: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         state.deleters.contains(&user).then_some(user).ok_or(ForbiddenError)
+///     }
+/// }
+///
+/// let permissions = Permissions {
+///     editors: HashSet::from([0]),
+///     deleters: HashSet::new(),
+/// };
+/// let probed = <(CanEdit, CanDelete)>::probe(&permissions, &0);
+/// assert_eq!(probed.iter().map(|(_, passed)| *passed).collect::<Vec<_>>(), vec![true, false]);
+/// ```
+pub trait Probe<State, Input: Clone> {
+    /// The type name of each filter, and whether it passes.
+    fn probe(state: &State, input: &Input) -> Vec<(&'static str, bool)>;
+}
+
+macro_rules! impl_probe {
+    ($($F:ident),*) => {
+        impl<State, Input: Clone, $($F: StateFilter<State, Input>),*> Probe<State, Input> for ($($F,)*) {
+            fn probe(state: &State, input: &Input) -> Vec<(&'static str, bool)> {
+                vec![$((std::any::type_name::<$F>(), $F::filter(state, input.clone()).is_ok())),*]
+            }
+        }
+    };
+}
+variadics_please::all_tuples!(impl_probe, 1, 8, F);
@@ -0,0 +1,147 @@
+use crate::{DescribableChain, StateFilter, ValidationError, Validator};
+
+impl<State, Input: std::hash::Hash, Filter: StateFilter<State, Input> + DescribableChain>
+    Validator<State, Input, Filter>
+where
+    Filter::ValidOutput: std::hash::Hash,
+{
+    /// Same as [`Validator::try_new`], but the input is hashed before it is filtered,
+    /// so [`ReceiptValidator::into_receipt`] can record it.
+    pub fn try_new_with_receipt(
+        state: State,
+        input: Input,
+    ) -> Result<ReceiptValidator<State, Input, Filter>, ValidationError<State, Filter::Error>> {
+        let input_hash = hash_value(&input);
+        Validator::try_new(state, input).map(|validator| ReceiptValidator {
+            validator,
+            input_hash,
+        })
+    }
+}
+
+/// A [`Validator`] along with the hash of the input it was made from,
+/// made by [`Validator::try_new_with_receipt`].
+pub struct ReceiptValidator<State, Input, Filter: StateFilter<State, Input>> {
+    validator: Validator<State, Input, Filter>,
+    input_hash: u64,
+}
+
+impl<State, Input, Filter: StateFilter<State, Input> + DescribableChain>
+    ReceiptValidator<State, Input, Filter>
+where
+    Filter::ValidOutput: std::hash::Hash,
+{
+    pub fn validator(&self) -> &Validator<State, Input, Filter> {
+        &self.validator
+    }
+    /// Drops the input hash, giving back the plain validator.
+    pub fn into_validator(self) -> Validator<State, Input, Filter> {
+        self.validator
+    }
+    /// Records that validation passed, for audit trails.
+    ///
+    /// With the `serde` feature, the receipt can be serialized.
+    /// ```
+    /// # use state_validation::{Condition, StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct ZeroError;
+    /// # impl std::error::Error for ZeroError {}
+    /// # impl std::fmt::Display for ZeroError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "zero")
+    /// #     }
+    /// # }
+    /// struct NonZero;
+    /// impl<State> StateFilter<State, u32> for NonZero {
+    ///     type ValidOutput = u32;
+    ///     type Error = ZeroError;
+    ///     fn filter(_state: &State, value: u32) -> Result<u32, Self::Error> {
+    ///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// let validator =
+    ///     Validator::<_, _, Condition<u32, NonZero>>::try_new_with_receipt((), 7).unwrap();
+    /// let (_state, receipt) = validator.into_receipt();
+    /// assert!(receipt.filters()[0].ends_with("NonZero"));
+    /// assert!(receipt.verify(&7, &7));
+    /// assert!(!receipt.verify(&7, &8));
+    /// ```
+    pub fn into_receipt(self) -> (State, ValidationReceipt) {
+        let receipt = ValidationReceipt {
+            filters: Filter::describe(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_secs()),
+            input_hash: self.input_hash,
+            output_hash: hash_value(&self.validator.value),
+        };
+        (self.validator.state, receipt)
+    }
+}
+
+/// A record that a [`Validator`] passed its filters.
+///
+/// Hashes are 64-bit FNV-1a over the bytes written by the value's [`Hash`](std::hash::Hash) impl,
+/// see [`ReceiptHasher`]. They are not cryptographic, so they catch a receipt being checked
+/// against the wrong values, but not a receipt that was tampered with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidationReceipt {
+    filters: Vec<&'static str>,
+    timestamp: u64,
+    input_hash: u64,
+    output_hash: u64,
+}
+
+impl ValidationReceipt {
+    /// Name of each filter, in the order they were run.
+    pub fn filters(&self) -> &[&'static str] {
+        &self.filters
+    }
+    /// Seconds since the Unix epoch when the receipt was made.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    /// Whether the receipt was made for this input and valid output.
+    pub fn verify(&self, input: &impl std::hash::Hash, output: &impl std::hash::Hash) -> bool {
+        self.input_hash == hash_value(input) && self.output_hash == hash_value(output)
+    }
+}
+
+/// The 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash used by [`ValidationReceipt`].
+///
+/// Unlike [`DefaultHasher`](std::hash::DefaultHasher), it is not randomly seeded,
+/// so the same bytes always hash to the same value.
+/// ```
+/// # use std::hash::Hasher;
+/// # use state_validation::ReceiptHasher;
+/// let mut hasher = ReceiptHasher::default();
+/// hasher.write(b"a");
+/// assert_eq!(hasher.finish(), 0xaf63dc4c8601ec8c);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiptHasher(u64);
+
+impl Default for ReceiptHasher {
+    fn default() -> Self {
+        ReceiptHasher(0xcbf29ce484222325)
+    }
+}
+
+impl std::hash::Hasher for ReceiptHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn hash_value(value: &impl std::hash::Hash) -> u64 {
+    use std::hash::{BuildHasher, BuildHasherDefault};
+    BuildHasherDefault::<ReceiptHasher>::default().hash_one(value)
+}
@@ -0,0 +1,92 @@
+use crate::StateFilter;
+
+/// A summary of many validations, counting the outcomes and keeping the errors.
+/// ```
+/// # use state_validation::{StateFilter, ValidationReport};
+/// # #[derive(Debug)]
+/// # struct OddError;
+/// # impl std::error::Error for OddError {}
+/// # impl std::fmt::Display for OddError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "odd")
+/// #     }
+/// # }
+/// struct Even;
+/// impl<State> StateFilter<State, u32> for Even {
+///     type ValidOutput = u32;
+///     type Error = OddError;
+///     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         if value % 2 == 0 { Ok(value) } else { Err(OddError) }
+///     }
+/// }
+///
+/// let report = ValidationReport::fold::<(), u32, Even>(&(), [0, 1, 2, 3]);
+/// assert_eq!(report.total(), 4);
+/// assert_eq!(report.successes(), 2);
+/// assert_eq!(report.failures().len(), 2);
+/// assert_eq!(report.success_rate(), 0.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidationReport<E> {
+    successes: usize,
+    failures: Vec<E>,
+}
+
+impl<E> Default for ValidationReport<E> {
+    fn default() -> Self {
+        ValidationReport {
+            successes: 0,
+            failures: Vec::new(),
+        }
+    }
+}
+
+impl<E> ValidationReport<E> {
+    pub fn new() -> Self {
+        ValidationReport::default()
+    }
+    /// Runs `F` on every input, recording each outcome.
+    pub fn fold<State, Input, F: StateFilter<State, Input, Error = E>>(
+        state: &State,
+        inputs: impl IntoIterator<Item = Input>,
+    ) -> Self {
+        let mut report = ValidationReport::new();
+        for input in inputs {
+            report.record(F::filter(state, input));
+        }
+        report
+    }
+    pub fn record_ok(&mut self) {
+        self.successes += 1;
+    }
+    pub fn record_err(&mut self, error: E) {
+        self.failures.push(error);
+    }
+    pub fn record<T>(&mut self, result: Result<T, E>) {
+        match result {
+            Ok(_) => self.record_ok(),
+            Err(error) => self.record_err(error),
+        }
+    }
+    pub fn total(&self) -> usize {
+        self.successes + self.failures.len()
+    }
+    pub fn successes(&self) -> usize {
+        self.successes
+    }
+    pub fn failures(&self) -> &[E] {
+        &self.failures
+    }
+    pub fn into_failures(self) -> Vec<E> {
+        self.failures
+    }
+    /// Ratio of successes to the total, from `0.0` to `1.0`.
+    /// An empty report has nothing that failed, so its rate is `1.0`.
+    pub fn success_rate(&self) -> f64 {
+        if self.total() == 0 {
+            1.0
+        } else {
+            self.successes as f64 / self.total() as f64
+        }
+    }
+}
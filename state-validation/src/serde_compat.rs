@@ -0,0 +1,52 @@
+use crate::{StateFilter, Validator};
+
+impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Filter>
+where
+    Filter::ValidOutput: serde::Serialize,
+{
+    /// Serializes the valid output as JSON straight into the writer.
+    /// ```
+    /// # use state_validation::{StateFilter, Validator};
+    /// # #[derive(Debug)]
+    /// # struct EmptyError;
+    /// # impl std::error::Error for EmptyError {}
+    /// # impl std::fmt::Display for EmptyError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "empty")
+    /// #     }
+    /// # }
+    /// #[derive(serde::Serialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    /// struct ValidUser;
+    /// impl<State> StateFilter<State, &str> for ValidUser {
+    ///     type ValidOutput = User;
+    ///     type Error = EmptyError;
+    ///     fn filter(_state: &State, name: &str) -> Result<Self::ValidOutput, Self::Error> {
+    ///         if name.is_empty() { Err(EmptyError) } else { Ok(User { name: name.to_string() }) }
+    ///     }
+    /// }
+    ///
+    /// let validator = Validator::<_, _, ValidUser>::try_new((), "admin").unwrap();
+    /// let mut body = Vec::new();
+    /// validator.write_valid_output(&mut body).unwrap();
+    /// assert_eq!(body, br#"{"name":"admin"}"#);
+    ///
+    /// let mut body = Vec::new();
+    /// validator
+    ///     .serialize_valid_output(&mut serde_json::Serializer::pretty(&mut body))
+    ///     .unwrap();
+    /// assert_eq!(String::from_utf8(body).unwrap(), "{\n  \"name\": \"admin\"\n}");
+    /// ```
+    pub fn write_valid_output<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        serde_json::to_writer(writer, self.valid_output()).map_err(std::io::Error::from)
+    }
+    /// Serializes the valid output with any [`serde::Serializer`].
+    pub fn serialize_valid_output<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(self.valid_output(), serializer)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::StateFilter;
+
+/// The approximate size of a value in bytes, cheap enough to measure before validating it.
+pub trait ApproxSize {
+    fn approx_size(&self) -> usize;
+}
+
+macro_rules! impl_approx_size_of {
+    ($($T:ty),*) => {
+        $(impl ApproxSize for $T {
+            fn approx_size(&self) -> usize {
+                std::mem::size_of::<$T>()
+            }
+        })*
+    };
+}
+impl_approx_size_of!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char
+);
+
+impl ApproxSize for str {
+    fn approx_size(&self) -> usize {
+        self.len()
+    }
+}
+impl ApproxSize for String {
+    fn approx_size(&self) -> usize {
+        self.len()
+    }
+}
+impl<T: ApproxSize> ApproxSize for [T] {
+    fn approx_size(&self) -> usize {
+        self.iter().map(ApproxSize::approx_size).sum()
+    }
+}
+impl<T: ApproxSize> ApproxSize for Vec<T> {
+    fn approx_size(&self) -> usize {
+        self.as_slice().approx_size()
+    }
+}
+impl<T: ApproxSize> ApproxSize for Option<T> {
+    fn approx_size(&self) -> usize {
+        self.as_ref().map_or(0, ApproxSize::approx_size)
+    }
+}
+impl<T: ApproxSize + ?Sized> ApproxSize for Box<T> {
+    fn approx_size(&self) -> usize {
+        (**self).approx_size()
+    }
+}
+impl<T: ApproxSize + ?Sized> ApproxSize for &T {
+    fn approx_size(&self) -> usize {
+        (**self).approx_size()
+    }
+}
+
+/// Rejects inputs larger than `MAX` bytes before running `F`,
+/// so untrusted inputs cannot make costly filters do unbounded work.
+///
+/// Use it as the outermost filter.
+/// ```
+/// # use state_validation::{SizeLimit, SizeLimitError, StateFilter};
+/// # #[derive(Debug)]
+/// # struct EmptyError;
+/// # impl std::error::Error for EmptyError {}
+/// # impl std::fmt::Display for EmptyError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "empty")
+/// #     }
+/// # }
+/// struct NotEmpty;
+/// impl<State> StateFilter<State, String> for NotEmpty {
+///     type ValidOutput = String;
+///     type Error = EmptyError;
+///     fn filter(_state: &State, value: String) -> Result<String, Self::Error> {
+///         if value.is_empty() { Err(EmptyError) } else { Ok(value) }
+///     }
+/// }
+///
+/// type Comment = SizeLimit<NotEmpty, 8>;
+/// assert!(Comment::filter(&(), "hello".to_string()).is_ok());
+/// let error = Comment::filter(&(), "hello world".to_string()).unwrap_err();
+/// assert!(matches!(error, SizeLimitError::TooLarge { max: 8, got: 11 }));
+/// assert!(matches!(Comment::filter(&(), String::new()), Err(SizeLimitError::Filter(EmptyError))));
+/// ```
+pub struct SizeLimit<F, const MAX: usize>(std::marker::PhantomData<F>);
+
+impl<State, Input: ApproxSize, F: StateFilter<State, Input>, const MAX: usize>
+    StateFilter<State, Input> for SizeLimit<F, MAX>
+{
+    type ValidOutput = F::ValidOutput;
+    type Error = SizeLimitError<F::Error>;
+    fn filter(state: &State, value: Input) -> Result<Self::ValidOutput, Self::Error> {
+        let size = value.approx_size();
+        if size > MAX {
+            return Err(SizeLimitError::TooLarge {
+                max: MAX,
+                got: size,
+            });
+        }
+        F::filter(state, value).map_err(SizeLimitError::Filter)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SizeLimitError<E: std::error::Error> {
+    #[error("input of {got} bytes is larger than the limit of {max} bytes")]
+    TooLarge { max: usize, got: usize },
+    #[error(transparent)]
+    Filter(E),
+}
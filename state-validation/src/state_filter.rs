@@ -1,5 +1,8 @@
-use crate::Condition;
+use crate::{ChainLink, Condition};
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a filter of `{Input}` for the state `{State}`"
+)]
 pub trait StateFilter<State, Input> {
     type ValidOutput;
     type Error: std::error::Error;
@@ -40,7 +43,9 @@ impl<
     Input1,
     F0: StateFilter<State, Input0>,
     F1: StateFilter<State, Input1>,
-> StateFilter<State, InitialInput> for (Condition<Input0, F0>, Condition<Input1, F1>)
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+> StateFilter<State, InitialInput> for (C0, C1)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -62,11 +67,11 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterTwoChainError::Filter0(e))
+            .map_err(StateFilterTwoChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterTwoChainError::Filter1(e))
+            .map_err(StateFilterTwoChainError::Filter1)
             })
     }
 }
@@ -86,12 +91,10 @@ impl<
     F0: StateFilter<State, Input0>,
     F1: StateFilter<State, Input1>,
     F2: StateFilter<State, Input2>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+> StateFilter<State, InitialInput> for (C0, C1, C2)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -129,16 +132,16 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterThreeChainError::Filter0(e))
+            .map_err(StateFilterThreeChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterThreeChainError::Filter1(e))
+            .map_err(StateFilterThreeChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterThreeChainError::Filter2(e))
+            .map_err(StateFilterThreeChainError::Filter2)
             })
     }
 }
@@ -162,13 +165,11 @@ impl<
     F1: StateFilter<State, Input1>,
     F2: StateFilter<State, Input2>,
     F3: StateFilter<State, Input3>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-        Condition<Input3, F3>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+    C3: ChainLink<Input = Input3, Filter = F3>,
+> StateFilter<State, InitialInput> for (C0, C1, C2, C3)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -221,21 +222,21 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFourChainError::Filter0(e))
+            .map_err(StateFilterFourChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFourChainError::Filter1(e))
+            .map_err(StateFilterFourChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFourChainError::Filter2(e))
+            .map_err(StateFilterFourChainError::Filter2)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F3::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFourChainError::Filter3(e))
+            .map_err(StateFilterFourChainError::Filter3)
             })
     }
 }
@@ -263,14 +264,12 @@ impl<
     F2: StateFilter<State, Input2>,
     F3: StateFilter<State, Input3>,
     F4: StateFilter<State, Input4>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-        Condition<Input3, F3>,
-        Condition<Input4, F4>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+    C3: ChainLink<Input = Input3, Filter = F3>,
+    C4: ChainLink<Input = Input4, Filter = F4>,
+> StateFilter<State, InitialInput> for (C0, C1, C2, C3, C4)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -337,26 +336,26 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFiveChainError::Filter0(e))
+            .map_err(StateFilterFiveChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFiveChainError::Filter1(e))
+            .map_err(StateFilterFiveChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFiveChainError::Filter2(e))
+            .map_err(StateFilterFiveChainError::Filter2)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F3::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFiveChainError::Filter3(e))
+            .map_err(StateFilterFiveChainError::Filter3)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F4::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterFiveChainError::Filter4(e))
+            .map_err(StateFilterFiveChainError::Filter4)
             })
     }
 }
@@ -388,15 +387,13 @@ impl<
     F3: StateFilter<State, Input3>,
     F4: StateFilter<State, Input4>,
     F5: StateFilter<State, Input5>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-        Condition<Input3, F3>,
-        Condition<Input4, F4>,
-        Condition<Input5, F5>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+    C3: ChainLink<Input = Input3, Filter = F3>,
+    C4: ChainLink<Input = Input4, Filter = F4>,
+    C5: ChainLink<Input = Input5, Filter = F5>,
+> StateFilter<State, InitialInput> for (C0, C1, C2, C3, C4, C5)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -477,31 +474,31 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter0(e))
+            .map_err(StateFilterSixChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter1(e))
+            .map_err(StateFilterSixChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter2(e))
+            .map_err(StateFilterSixChainError::Filter2)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F3::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter3(e))
+            .map_err(StateFilterSixChainError::Filter3)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F4::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter4(e))
+            .map_err(StateFilterSixChainError::Filter4)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F5::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSixChainError::Filter5(e))
+            .map_err(StateFilterSixChainError::Filter5)
             })
     }
 }
@@ -537,16 +534,14 @@ impl<
     F4: StateFilter<State, Input4>,
     F5: StateFilter<State, Input5>,
     F6: StateFilter<State, Input6>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-        Condition<Input3, F3>,
-        Condition<Input4, F4>,
-        Condition<Input5, F5>,
-        Condition<Input6, F6>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+    C3: ChainLink<Input = Input3, Filter = F3>,
+    C4: ChainLink<Input = Input4, Filter = F4>,
+    C5: ChainLink<Input = Input5, Filter = F5>,
+    C6: ChainLink<Input = Input6, Filter = F6>,
+> StateFilter<State, InitialInput> for (C0, C1, C2, C3, C4, C5, C6)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -641,36 +636,36 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter0(e))
+            .map_err(StateFilterSevenChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter1(e))
+            .map_err(StateFilterSevenChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter2(e))
+            .map_err(StateFilterSevenChainError::Filter2)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F3::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter3(e))
+            .map_err(StateFilterSevenChainError::Filter3)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F4::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter4(e))
+            .map_err(StateFilterSevenChainError::Filter4)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F5::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter5(e))
+            .map_err(StateFilterSevenChainError::Filter5)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F6::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterSevenChainError::Filter6(e))
+            .map_err(StateFilterSevenChainError::Filter6)
             })
     }
 }
@@ -710,17 +705,15 @@ impl<
     F5: StateFilter<State, Input5>,
     F6: StateFilter<State, Input6>,
     F7: StateFilter<State, Input7>,
-> StateFilter<State, InitialInput>
-    for (
-        Condition<Input0, F0>,
-        Condition<Input1, F1>,
-        Condition<Input2, F2>,
-        Condition<Input3, F3>,
-        Condition<Input4, F4>,
-        Condition<Input5, F5>,
-        Condition<Input6, F6>,
-        Condition<Input7, F7>,
-    )
+    C0: ChainLink<Input = Input0, Filter = F0>,
+    C1: ChainLink<Input = Input1, Filter = F1>,
+    C2: ChainLink<Input = Input2, Filter = F2>,
+    C3: ChainLink<Input = Input3, Filter = F3>,
+    C4: ChainLink<Input = Input4, Filter = F4>,
+    C5: ChainLink<Input = Input5, Filter = F5>,
+    C6: ChainLink<Input = Input6, Filter = F6>,
+    C7: ChainLink<Input = Input7, Filter = F7>,
+> StateFilter<State, InitialInput> for (C0, C1, C2, C3, C4, C5, C6, C7)
 where
     InitialInput: StateFilterInputConversion<Input0>,
     <InitialInput as StateFilterInputConversion<Input0>>::Remainder:
@@ -829,41 +822,41 @@ where
         let (input, remainder) = value.split_take();
         F0::filter(state, input)
             .map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter0(e))
+            .map_err(StateFilterEightChainError::Filter0)
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F1::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter1(e))
+            .map_err(StateFilterEightChainError::Filter1)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F2::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter2(e))
+            .map_err(StateFilterEightChainError::Filter2)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F3::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter3(e))
+            .map_err(StateFilterEightChainError::Filter3)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F4::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter4(e))
+            .map_err(StateFilterEightChainError::Filter4)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F5::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter5(e))
+            .map_err(StateFilterEightChainError::Filter5)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F6::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter6(e))
+            .map_err(StateFilterEightChainError::Filter6)
             })
             .and_then(|v| {
                 let (input, remainder) = v.split_take();
                 F7::filter(state, input).map(|v| remainder.combine(v))
-            .map_err(|e| StateFilterEightChainError::Filter7(e))
+            .map_err(StateFilterEightChainError::Filter7)
             })
     }
 }
@@ -910,3 +903,31 @@ impl<T> StateFilterInputCombination<T> for () {
         value
     }
 }
+
+/// A [`StateFilter`] which only needs to read its input,
+/// so the caller keeps ownership of it.
+///
+/// Use [`ByRef`] to run it as a [`StateFilter`] over `&Input`.
+pub trait StateFilterRef<State, Input> {
+    type ValidOutput;
+    type Error: std::error::Error;
+    fn filter(state: &State, value: &Input) -> Result<Self::ValidOutput, Self::Error>;
+}
+
+/// Runs the [`StateFilterRef`] `F` as a [`StateFilter`] over `&Input`.
+pub struct ByRef<F>(std::marker::PhantomData<F>);
+
+impl<'a, State, Input, F: StateFilterRef<State, Input>> StateFilter<State, &'a Input> for ByRef<F> {
+    type ValidOutput = F::ValidOutput;
+    type Error = F::Error;
+    fn filter(state: &State, value: &'a Input) -> Result<Self::ValidOutput, Self::Error> {
+        F::filter(state, value)
+    }
+}
+
+/// A [`StateFilter`] which must update the state as it validates, such as to count requests.
+pub trait StateFilterMut<State, Input> {
+    type ValidOutput;
+    type Error: std::error::Error;
+    fn filter(state: &mut State, value: Input) -> Result<Self::ValidOutput, Self::Error>;
+}
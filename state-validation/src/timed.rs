@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use crate::{SameFilter, StateFilter, ValidAction, ValidationError, Validator};
+
+/// The output of [`run_timed`], along with how long it took.
+pub type TimedRun<Output, State, E> = (Result<Output, ValidationError<State, E>>, Duration);
+
+/// Validates the input and executes the action, measuring how long both took together,
+/// such as to keep track of the latency of each kind of operation.
+/// ```
+/// # use state_validation::{StateFilter, ValidAction, run_timed};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// # struct NonZero;
+/// # impl<State> StateFilter<State, u32> for NonZero {
+/// #     type ValidOutput = u32;
+/// #     type Error = ZeroError;
+/// #     fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+/// #         if value == 0 { Err(ZeroError) } else { Ok(value) }
+/// #     }
+/// # }
+/// struct Deposit;
+/// impl ValidAction<u32, u32> for Deposit {
+///     type Filter = NonZero;
+///     type Output = u32;
+///     fn with_valid_input(self, balance: u32, amount: u32) -> Self::Output {
+///         balance + amount
+///     }
+/// }
+///
+/// let (balance, elapsed) = run_timed(10, 5, Deposit);
+/// assert_eq!(balance.unwrap(), 15);
+/// # let _ = elapsed;
+/// let (error, _elapsed) = run_timed(10, 0, Deposit);
+/// assert_eq!(error.unwrap_err().state, 10);
+/// ```
+pub fn run_timed<
+    State,
+    Input,
+    Filter: StateFilter<State, Input>,
+    Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>>,
+>(
+    state: State,
+    input: Input,
+    valid_action: Action,
+) -> TimedRun<Action::Output, State, Filter::Error> {
+    let start = Instant::now();
+    let output = Validator::<State, Input, Filter>::try_new(state, input)
+        .map(|validator| validator.execute(valid_action));
+    (output, start.elapsed())
+}
+
+impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Filter> {
+    /// Same as [`Validator::execute`], but also measures how long the action took.
+    pub fn execute_timed<
+        Action: ValidAction<State, Input, Filter: SameFilter<State, Input, Filter>>,
+    >(
+        self,
+        valid_action: Action,
+    ) -> (Action::Output, Duration) {
+        let start = Instant::now();
+        let output = self.execute(valid_action);
+        (output, start.elapsed())
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    marker::PhantomData,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use crate::StateFilter;
+
+/// Runs the filter `F` as a [`tower::Service`], against state shared with the rest of the service.
+///
+/// The filter runs synchronously, so the returned future is always ready.
+/// ```
+/// # use std::sync::Arc;
+/// # use state_validation::{FilterService, StateFilter};
+/// # use tower::Service;
+/// # #[derive(Debug, PartialEq)]
+/// # struct TakenError;
+/// # impl std::error::Error for TakenError {}
+/// # impl std::fmt::Display for TakenError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "taken")
+/// #     }
+/// # }
+/// struct Usernames(Vec<String>);
+/// struct Available;
+/// impl StateFilter<Usernames, String> for Available {
+///     type ValidOutput = String;
+///     type Error = TakenError;
+///     fn filter(state: &Usernames, name: String) -> Result<Self::ValidOutput, Self::Error> {
+///         if state.0.contains(&name) { Err(TakenError) } else { Ok(name) }
+///     }
+/// }
+///
+/// let state = Arc::new(Usernames(vec!["admin".to_string()]));
+/// let mut service = FilterService::<_, Available>::new(state);
+/// let response = service.call("guest".to_string()).into_inner();
+/// assert_eq!(response, Ok("guest".to_string()));
+/// let response = service.call("admin".to_string()).into_inner();
+/// assert_eq!(response, Err(TakenError));
+/// ```
+pub struct FilterService<State, F> {
+    state: Arc<State>,
+    _p: PhantomData<fn() -> F>,
+}
+
+impl<State, F> FilterService<State, F> {
+    pub fn new(state: Arc<State>) -> Self {
+        FilterService {
+            state,
+            _p: PhantomData,
+        }
+    }
+    pub fn state(&self) -> &Arc<State> {
+        &self.state
+    }
+}
+
+impl<State, F> Clone for FilterService<State, F> {
+    fn clone(&self) -> Self {
+        FilterService::new(self.state.clone())
+    }
+}
+
+impl<State: Send + Sync, Input, F: StateFilter<State, Input>> tower::Service<Input>
+    for FilterService<State, F>
+{
+    type Response = F::ValidOutput;
+    type Error = F::Error;
+    type Future = std::future::Ready<Result<F::ValidOutput, F::Error>>;
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, input: Input) -> Self::Future {
+        std::future::ready(F::filter(&self.state, input))
+    }
+}
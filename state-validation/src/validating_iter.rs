@@ -0,0 +1,56 @@
+use crate::StateFilter;
+
+/// Runs the filter `F` on each item of an iterator as it is pulled,
+/// yielding the outputs and errors alike, so validation can be one step of a lazy pipeline.
+/// ```
+/// # use state_validation::{StateFilter, ValidatingIter};
+/// # #[derive(Debug)]
+/// # struct ZeroError;
+/// # impl std::error::Error for ZeroError {}
+/// # impl std::fmt::Display for ZeroError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "zero")
+/// #     }
+/// # }
+/// struct NonZero;
+/// impl<State> StateFilter<State, u32> for NonZero {
+///     type ValidOutput = u32;
+///     type Error = ZeroError;
+///     fn filter(_state: &State, value: u32) -> Result<u32, Self::Error> {
+///         if value == 0 { Err(ZeroError) } else { Ok(value) }
+///     }
+/// }
+///
+/// let mut validated = ValidatingIter::<_, NonZero, _>::new([1, 0, 2].into_iter(), &());
+/// assert_eq!(validated.next().unwrap().unwrap(), 1);
+/// assert!(validated.next().unwrap().is_err());
+/// let doubled: Vec<_> = validated.map(|value| value.map(|value| value * 2)).collect();
+/// assert_eq!(doubled.len(), 1);
+/// ```
+pub struct ValidatingIter<'s, I, F, State> {
+    inputs: I,
+    state: &'s State,
+    _p: std::marker::PhantomData<F>,
+}
+
+impl<'s, I: Iterator, F: StateFilter<State, I::Item>, State> ValidatingIter<'s, I, F, State> {
+    pub fn new(inputs: I, state: &'s State) -> Self {
+        ValidatingIter {
+            inputs,
+            state,
+            _p: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator, F: StateFilter<State, I::Item>, State> Iterator
+    for ValidatingIter<'_, I, F, State>
+{
+    type Item = Result<F::ValidOutput, F::Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inputs.next().map(|input| F::filter(self.state, input))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
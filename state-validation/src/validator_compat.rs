@@ -0,0 +1,37 @@
+use crate::StateFilter;
+
+/// Runs [`validator::Validate::validate`] as a [`StateFilter`],
+/// so field-level `#[validate]` rules can be used alongside state-dependent filters.
+/// ```
+/// # use state_validation::{FromValidate, Validator};
+/// use validator::{Validate, ValidationError, ValidationErrors};
+/// struct Signup {
+///     username: String,
+/// }
+/// // Usually generated with `#[derive(Validate)]`.
+/// impl Validate for Signup {
+///     fn validate(&self) -> Result<(), ValidationErrors> {
+///         let mut errors = ValidationErrors::new();
+///         if self.username.is_empty() {
+///             errors.add("username", ValidationError::new("length"));
+///         }
+///         if errors.is_empty() { Ok(()) } else { Err(errors) }
+///     }
+/// }
+///
+/// let signup = Signup { username: "admin".to_string() };
+/// let validator = Validator::<_, _, FromValidate>::try_new((), signup).unwrap();
+/// assert_eq!(validator.valid_output().username, "admin");
+/// let signup = Signup { username: String::new() };
+/// let Err(error) = Validator::<_, _, FromValidate>::try_new((), signup) else { unreachable!() };
+/// assert!(error.error.field_errors().contains_key("username"));
+/// ```
+pub struct FromValidate;
+
+impl<State, T: validator::Validate> StateFilter<State, T> for FromValidate {
+    type ValidOutput = T;
+    type Error = validator::ValidationErrors;
+    fn filter(_state: &State, value: T) -> Result<Self::ValidOutput, Self::Error> {
+        value.validate().map(|()| value)
+    }
+}
@@ -0,0 +1,92 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{StateFilter, ValidationError};
+
+/// A [`Validator`](crate::Validator) for state that lives behind a guard,
+/// such as a [`RwLockReadGuard`](std::sync::RwLockReadGuard) or [`RwLockWriteGuard`](std::sync::RwLockWriteGuard).
+///
+/// A read guard only permits reading the valid output.
+/// A write guard additionally permits mutating the state with [`ValidatorGuard::execute_mut`].
+/// ```
+/// # use std::sync::RwLock;
+/// # use state_validation::{StateFilter, ValidatorGuard};
+/// # #[derive(Debug)]
+/// # struct NotPositiveError;
+/// # impl std::error::Error for NotPositiveError {}
+/// # impl std::fmt::Display for NotPositiveError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "not positive")
+/// #     }
+/// # }
+/// struct Positive;
+/// impl<State> StateFilter<State, i32> for Positive {
+///     type ValidOutput = u32;
+///     type Error = NotPositiveError;
+///     fn filter(_state: &State, value: i32) -> Result<Self::ValidOutput, Self::Error> {
+///         u32::try_from(value).map_err(|_| NotPositiveError)
+///     }
+/// }
+///
+/// let counter = RwLock::new(0u32);
+///
+/// let validator = ValidatorGuard::<_, _, _, Positive>::try_new(counter.read().unwrap(), 5).unwrap();
+/// assert_eq!(*validator.valid_output(), 5);
+/// drop(validator);
+///
+/// let validator = ValidatorGuard::<_, _, _, Positive>::try_new(counter.write().unwrap(), 5).unwrap();
+/// validator.execute_mut(|counter, amount| *counter += amount);
+/// assert_eq!(*counter.read().unwrap(), 5);
+/// ```
+pub struct ValidatorGuard<
+    State,
+    Guard: Deref<Target = State>,
+    Input,
+    Filter: StateFilter<State, Input>,
+> {
+    guard: Guard,
+    value: Filter::ValidOutput,
+    _p: std::marker::PhantomData<fn() -> (Input, Filter)>,
+}
+
+impl<State, Guard: Deref<Target = State>, Input, Filter: StateFilter<State, Input>>
+    ValidatorGuard<State, Guard, Input, Filter>
+{
+    pub fn try_new(
+        guard: Guard,
+        input: Input,
+    ) -> Result<Self, ValidationError<Guard, Filter::Error>> {
+        match Filter::filter(&guard, input) {
+            Ok(value) => Ok(ValidatorGuard {
+                guard,
+                value,
+                _p: std::marker::PhantomData,
+            }),
+            Err(error) => Err(ValidationError {
+                state: guard,
+                error,
+            }),
+        }
+    }
+    pub fn state(&self) -> &State {
+        &self.guard
+    }
+    pub fn valid_output(&self) -> &Filter::ValidOutput {
+        &self.value
+    }
+    /// Releases the guard, keeping only the valid output.
+    pub fn into_valid_output(self) -> Filter::ValidOutput {
+        self.value
+    }
+}
+
+impl<State, Guard: DerefMut<Target = State>, Input, Filter: StateFilter<State, Input>>
+    ValidatorGuard<State, Guard, Input, Filter>
+{
+    /// Mutates the state behind the guard using the valid output.
+    pub fn execute_mut<Output>(
+        mut self,
+        action: impl FnOnce(&mut State, Filter::ValidOutput) -> Output,
+    ) -> Output {
+        action(&mut self.guard, self.value)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{MutAction, SameFilter, StateFilter, Validator};
+
+/// A [`Validator`] which mutably borrows the state instead of taking ownership of it,
+/// so an action can mutate the state in place.
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{MutAction, StateFilter, Validator};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// struct UserStorage(HashMap<u32, String>);
+/// struct App {
+///     users: UserStorage,
+/// }
+/// struct UserExists;
+/// impl StateFilter<UserStorage, u32> for UserExists {
+///     type ValidOutput = u32;
+///     type Error = UserDoesNotExistError;
+///     fn filter(state: &UserStorage, user_id: u32) -> Result<Self::ValidOutput, Self::Error> {
+///         state.0.contains_key(&user_id).then_some(user_id).ok_or(UserDoesNotExistError)
+///     }
+/// }
+/// struct RemoveUser;
+/// impl MutAction<UserStorage, u32> for RemoveUser {
+///     type Filter = UserExists;
+///     type Output = String;
+///     fn with_valid_input(self, state: &mut UserStorage, user_id: u32) -> Self::Output {
+///         state.0.remove(&user_id).unwrap()
+///     }
+/// }
+///
+/// let mut app = App { users: UserStorage(HashMap::from([(0, "admin".to_string())])) };
+/// let removed = Validator::try_new_mut(&mut app.users, 0).unwrap().execute(RemoveUser);
+/// assert_eq!(removed, "admin");
+/// assert!(app.users.0.is_empty());
+/// ```
+pub struct ValidatorMut<'s, State, Input, Filter: StateFilter<State, Input>> {
+    state: &'s mut State,
+    value: Filter::ValidOutput,
+    _p: std::marker::PhantomData<fn() -> Input>,
+}
+
+impl<'s, State, Input, Filter: StateFilter<State, Input>> ValidatorMut<'s, State, Input, Filter> {
+    pub fn try_new(state: &'s mut State, input: Input) -> Result<Self, Filter::Error> {
+        Ok(ValidatorMut {
+            value: Filter::filter(state, input)?,
+            state,
+            _p: std::marker::PhantomData,
+        })
+    }
+    pub fn state(&self) -> &State {
+        self.state
+    }
+    pub fn valid_output(&self) -> &Filter::ValidOutput {
+        &self.value
+    }
+    pub fn execute<Action: MutAction<State, Input, Filter: SameFilter<State, Input, Filter>>>(
+        self,
+        mut_action: Action,
+    ) -> Action::Output {
+        mut_action.with_valid_input(self.state, Action::Filter::same_output(self.value))
+    }
+}
+
+impl<State, Input, Filter: StateFilter<State, Input>> Validator<State, Input, Filter> {
+    /// Same as [`ValidatorMut::try_new`].
+    pub fn try_new_mut(
+        state: &mut State,
+        input: Input,
+    ) -> Result<ValidatorMut<'_, State, Input, Filter>, Filter::Error> {
+        ValidatorMut::try_new(state, input)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::EventAction;
+
+/// A [`StateFilter`](crate::StateFilter) whose output may borrow from the state,
+/// so validated data does not need to be cloned out of it.
+pub trait StateFilterBorrow<State, Input> {
+    type ValidOutput<'s>
+    where
+        State: 's;
+    type Error: std::error::Error;
+    fn filter<'s>(state: &'s State, value: Input) -> Result<Self::ValidOutput<'s>, Self::Error>;
+}
+
+/// A [`Validator`](crate::Validator) which only borrows the state,
+/// holding a valid output which may borrow from it.
+///
+/// Since the state is only borrowed, it can only be read.
+/// ```
+/// # use std::collections::HashMap;
+/// # use state_validation::{StateFilterBorrow, ValidatorRef};
+/// # #[derive(Debug)]
+/// # struct UserDoesNotExistError;
+/// # impl std::error::Error for UserDoesNotExistError {}
+/// # impl std::fmt::Display for UserDoesNotExistError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "user does not exist")
+/// #     }
+/// # }
+/// struct User {
+///     username: String,
+/// }
+/// struct UserStorage(HashMap<u32, User>);
+/// struct UserExists;
+/// impl StateFilterBorrow<UserStorage, u32> for UserExists {
+///     type ValidOutput<'s> = &'s User;
+///     type Error = UserDoesNotExistError;
+///     fn filter<'s>(state: &'s UserStorage, user_id: u32) -> Result<&'s User, Self::Error> {
+///         state.0.get(&user_id).ok_or(UserDoesNotExistError)
+///     }
+/// }
+///
+/// let users = UserStorage(HashMap::from([(0, User { username: "admin".to_string() })]));
+/// let validator = ValidatorRef::<_, _, UserExists>::try_new(&users, 0).unwrap();
+/// let username_len = validator.execute(|_users, user| user.username.len());
+/// assert_eq!(username_len, 5);
+/// ```
+pub struct ValidatorRef<'s, State, Input, Filter: StateFilterBorrow<State, Input>> {
+    state: &'s State,
+    value: Filter::ValidOutput<'s>,
+    _p: std::marker::PhantomData<fn() -> Input>,
+}
+
+impl<'s, State, Input, Filter: StateFilterBorrow<State, Input>>
+    ValidatorRef<'s, State, Input, Filter>
+{
+    pub fn try_new(state: &'s State, input: Input) -> Result<Self, Filter::Error> {
+        Ok(ValidatorRef {
+            state,
+            value: Filter::filter(state, input)?,
+            _p: std::marker::PhantomData,
+        })
+    }
+    pub fn state(&self) -> &'s State {
+        self.state
+    }
+    pub fn valid_output(&self) -> &Filter::ValidOutput<'s> {
+        &self.value
+    }
+    pub fn into_valid_output(self) -> Filter::ValidOutput<'s> {
+        self.value
+    }
+    /// Executes a read-only action with the borrowed state and valid output.
+    pub fn execute<Output>(
+        self,
+        action: impl FnOnce(&'s State, Filter::ValidOutput<'s>) -> Output,
+    ) -> Output {
+        action(self.state, self.value)
+    }
+    /// Executes an action which emits events instead of changing the state.
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use state_validation::{EventAction, StateFilterBorrow, ValidatorRef};
+    /// # #[derive(Debug)]
+    /// # struct AccountDoesNotExistError;
+    /// # impl std::error::Error for AccountDoesNotExistError {}
+    /// # impl std::fmt::Display for AccountDoesNotExistError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// #         write!(f, "account does not exist")
+    /// #     }
+    /// # }
+    /// struct Accounts(HashMap<u32, u32>);
+    /// struct AccountExists;
+    /// impl StateFilterBorrow<Accounts, u32> for AccountExists {
+    ///     type ValidOutput<'s> = (u32, &'s u32);
+    ///     type Error = AccountDoesNotExistError;
+    ///     fn filter<'s>(state: &'s Accounts, id: u32) -> Result<(u32, &'s u32), Self::Error> {
+    ///         state.0.get(&id).map(|balance| (id, balance)).ok_or(AccountDoesNotExistError)
+    ///     }
+    /// }
+    /// #[derive(Debug, PartialEq)]
+    /// enum Event {
+    ///     Withdrawn { id: u32, amount: u32 },
+    ///     Closed { id: u32 },
+    /// }
+    /// struct CloseAccount;
+    /// impl EventAction<Accounts, u32> for CloseAccount {
+    ///     type Filter = AccountExists;
+    ///     type Event = Event;
+    ///     fn emit<'s>(self, _state: &'s Accounts, (id, balance): (u32, &'s u32)) -> Vec<Event> {
+    ///         vec![Event::Withdrawn { id, amount: *balance }, Event::Closed { id }]
+    ///     }
+    /// }
+    ///
+    /// let accounts = Accounts(HashMap::from([(0, 25)]));
+    /// let events = ValidatorRef::<_, _, AccountExists>::try_new(&accounts, 0)
+    ///     .unwrap()
+    ///     .emit(CloseAccount);
+    /// assert_eq!(events, vec![Event::Withdrawn { id: 0, amount: 25 }, Event::Closed { id: 0 }]);
+    /// assert_eq!(accounts.0[&0], 25);
+    /// ```
+    pub fn emit<Action: EventAction<State, Input, Filter = Filter>>(
+        self,
+        action: Action,
+    ) -> Vec<Action::Event> {
+        action.emit(self.state, self.value)
+    }
+}
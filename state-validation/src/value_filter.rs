@@ -0,0 +1,60 @@
+/// A filter carrying its own configuration, such as a limit read from a config file,
+/// which transforms a value into another value of the same type.
+///
+/// Since every filter of the same type outputs the same type,
+/// an array of them runs one after the other, each taking the output of the last.
+/// ```
+/// # use state_validation::{IndexedFilterError, ValueFilter};
+/// # #[derive(Debug)]
+/// # struct TooLongError;
+/// # impl std::error::Error for TooLongError {}
+/// # impl std::fmt::Display for TooLongError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         write!(f, "too long")
+/// #     }
+/// # }
+/// enum Step {
+///     Trim,
+///     MaxLen(usize),
+/// }
+/// impl<State> ValueFilter<State, String> for Step {
+///     type Error = TooLongError;
+///     fn filter(&self, _state: &State, value: String) -> Result<String, Self::Error> {
+///         match self {
+///             Step::Trim => Ok(value.trim().to_string()),
+///             Step::MaxLen(max) if value.len() > *max => Err(TooLongError),
+///             Step::MaxLen(_) => Ok(value),
+///         }
+///     }
+/// }
+///
+/// let pipeline = [Step::Trim, Step::MaxLen(8), Step::MaxLen(4)];
+/// assert_eq!(pipeline.filter(&(), "  abc  ".to_string()).unwrap(), "abc");
+/// let error = pipeline.filter(&(), " abcdef ".to_string()).unwrap_err();
+/// assert_eq!(error.index, 2);
+/// ```
+pub trait ValueFilter<State, T> {
+    type Error: std::error::Error;
+    fn filter(&self, state: &State, value: T) -> Result<T, Self::Error>;
+}
+
+impl<State, T, F: ValueFilter<State, T>, const N: usize> ValueFilter<State, T> for [F; N] {
+    type Error = IndexedFilterError<F::Error>;
+    fn filter(&self, state: &State, value: T) -> Result<T, Self::Error> {
+        self.iter()
+            .enumerate()
+            .try_fold(value, |value, (index, filter)| {
+                filter
+                    .filter(state, value)
+                    .map_err(|error| IndexedFilterError { index, error })
+            })
+    }
+}
+
+/// The error of a filter in an array, along with its index.
+#[derive(thiserror::Error, Debug)]
+#[error("filter {index} failed: {error}")]
+pub struct IndexedFilterError<E: std::error::Error> {
+    pub index: usize,
+    pub error: E,
+}
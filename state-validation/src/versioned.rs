@@ -0,0 +1,90 @@
+use crate::StateFilter;
+
+/// State stamped with a version, which changes every time the state is modified.
+///
+/// Used with [`VersionMatches`] for optimistic locking,
+/// rejecting an input if the state was modified since the input was made.
+/// ```
+/// # use state_validation::{ExpectsVersion, StateFilter, VersionMatches, VersionedState};
+/// #[derive(Debug)]
+/// struct Edit {
+///     read_version: u64,
+///     text: String,
+/// }
+/// impl ExpectsVersion<u64> for Edit {
+///     fn expected_version(&self) -> &u64 {
+///         &self.read_version
+///     }
+/// }
+///
+/// let mut document = VersionedState::new(String::new(), 0);
+/// let edit = Edit { read_version: 0, text: "hello".to_string() };
+/// let edit = VersionMatches::filter(&document, edit).unwrap();
+/// document.modify(1, |document| document.push_str(&edit.text));
+///
+/// let stale_edit = Edit { read_version: 0, text: "world".to_string() };
+/// let error = VersionMatches::filter(&document, stale_edit).unwrap_err();
+/// assert_eq!((error.expected, error.current), (0, 1));
+/// ```
+pub struct VersionedState<S, V = u64> {
+    state: S,
+    version: V,
+}
+
+impl<S, V> VersionedState<S, V> {
+    pub fn new(state: S, version: V) -> Self {
+        VersionedState { state, version }
+    }
+    pub fn version(&self) -> &V {
+        &self.version
+    }
+    /// Modifies the state, stamping it with the new version.
+    pub fn modify<Output>(&mut self, version: V, f: impl FnOnce(&mut S) -> Output) -> Output {
+        self.version = version;
+        f(&mut self.state)
+    }
+    pub fn into_inner(self) -> (S, V) {
+        (self.state, self.version)
+    }
+}
+impl<S, V> std::ops::Deref for VersionedState<S, V> {
+    type Target = S;
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// An input made from a specific version of the state.
+pub trait ExpectsVersion<V> {
+    fn expected_version(&self) -> &V;
+}
+
+/// Passes if the version the input expects is the current version of the state.
+pub struct VersionMatches;
+
+impl<S, V: PartialEq + Clone + std::fmt::Debug, Input: ExpectsVersion<V>>
+    StateFilter<VersionedState<S, V>, Input> for VersionMatches
+{
+    type ValidOutput = Input;
+    type Error = VersionConflictError<V>;
+    fn filter(
+        state: &VersionedState<S, V>,
+        value: Input,
+    ) -> Result<Self::ValidOutput, Self::Error> {
+        if value.expected_version() == state.version() {
+            Ok(value)
+        } else {
+            Err(VersionConflictError {
+                expected: value.expected_version().clone(),
+                current: state.version().clone(),
+            })
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("expected version {expected:?}, but the state is at version {current:?}")]
+pub struct VersionConflictError<V: std::fmt::Debug> {
+    pub expected: V,
+    pub current: V,
+}
@@ -0,0 +1,41 @@
+use state_validation::{StateFilter, ValidAction, Validator};
+
+#[derive(Debug)]
+struct InvalidError;
+impl std::error::Error for InvalidError {}
+impl std::fmt::Display for InvalidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid")
+    }
+}
+
+struct NonZero;
+impl<State> StateFilter<State, u32> for NonZero {
+    type ValidOutput = u32;
+    type Error = InvalidError;
+    fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+        if value == 0 { Err(InvalidError) } else { Ok(value) }
+    }
+}
+
+struct Even;
+impl<State> StateFilter<State, u32> for Even {
+    type ValidOutput = u32;
+    type Error = InvalidError;
+    fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+        if value % 2 == 0 { Ok(value) } else { Err(InvalidError) }
+    }
+}
+
+struct Halve;
+impl ValidAction<(), u32> for Halve {
+    type Filter = Even;
+    type Output = u32;
+    fn with_valid_input(self, _state: (), value: u32) -> Self::Output {
+        value / 2
+    }
+}
+
+fn main() {
+    Validator::<_, _, NonZero>::try_new((), 4).unwrap().execute(Halve);
+}
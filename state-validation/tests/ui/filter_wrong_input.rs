@@ -0,0 +1,30 @@
+use state_validation::{StateFilter, ValidAction};
+
+#[derive(Debug)]
+struct ZeroError;
+impl std::error::Error for ZeroError {}
+impl std::fmt::Display for ZeroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "zero")
+    }
+}
+
+struct NonZero;
+impl<State> StateFilter<State, u32> for NonZero {
+    type ValidOutput = u32;
+    type Error = ZeroError;
+    fn filter(_state: &State, value: u32) -> Result<Self::ValidOutput, Self::Error> {
+        if value == 0 { Err(ZeroError) } else { Ok(value) }
+    }
+}
+
+struct Double;
+impl ValidAction<(), u64> for Double {
+    type Filter = NonZero;
+    type Output = u64;
+    fn with_valid_input(self, _state: (), value: u32) -> Self::Output {
+        u64::from(value) * 2
+    }
+}
+
+fn main() {}